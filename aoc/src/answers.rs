@@ -0,0 +1,77 @@
+use common::{ParseError, Part};
+use std::collections::HashMap;
+
+/// The known-correct answers for the real puzzle inputs
+pub const ANSWERS: &str = include_str!("../answers.txt");
+
+/// Answers that have been confirmed correct, keyed by day and part
+///
+/// The file format is one answer per line: the day, the part and the answer, separated by
+/// whitespace. Blank lines and lines starting with `#` are ignored.
+#[derive(Debug, Default)]
+pub struct Answers {
+    answers: HashMap<(u8, u8), String>,
+}
+
+impl Answers {
+    pub fn parse(s: &str) -> Result<Self, ParseError> {
+        let mut answers = HashMap::new();
+        for line in s.lines() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+
+            let fields = trimmed.split_whitespace().collect::<Vec<_>>();
+            let [day, part, answer] = fields[..] else {
+                return Err(
+                    ParseError::new(trimmed, "expected a day, a part and an answer")
+                        .within(s, trimmed),
+                );
+            };
+            let day = day
+                .parse()
+                .map_err(|_| ParseError::new(day, "expected a day").within(s, day))?;
+            let part = match part {
+                "1" => 1,
+                "2" => 2,
+                _ => return Err(ParseError::new(part, "expected part 1 or 2").within(s, part)),
+            };
+            if answers.insert((day, part), answer.to_string()).is_some() {
+                return Err(
+                    ParseError::new(trimmed, "this answer has already been recorded")
+                        .within(s, trimmed),
+                );
+            }
+        }
+        Ok(Answers { answers })
+    }
+
+    /// The recorded answer, if there is one
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.answers.get(&(day, part.number())).map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse("# day part answer\n1 1 3\n\n1 2 6\n5 2 14\n").unwrap();
+        assert_eq!(answers.get(1, Part::One), Some("3"));
+        assert_eq!(answers.get(1, Part::Two), Some("6"));
+        assert_eq!(answers.get(5, Part::One), None);
+        assert_eq!(answers.get(5, Part::Two), Some("14"));
+
+        let err = Answers::parse("1 1 3\n1 3 6\n").unwrap_err();
+        assert_eq!((err.line(), err.column(), err.text()), (2, 3, "3"));
+
+        let err = Answers::parse("1 1 3\n1 1\n").unwrap_err();
+        assert_eq!((err.line(), err.column()), (2, 1));
+
+        let err = Answers::parse("1 1 3\n1 1 4\n").unwrap_err();
+        assert_eq!(err.line(), 2);
+    }
+}
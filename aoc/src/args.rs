@@ -0,0 +1,303 @@
+use common::{InputSource, Part};
+use std::path::PathBuf;
+
+pub const USAGE: &str = "\
+usage: aoc [run] [--day N] [--part 1|2|all] [--input PATH|-] [--format text|json]
+       aoc verify [--day N] [--part 1|2|all] [--input PATH|-] [--answers PATH|-]
+       aoc bench [--day N] [--part 1|2|all] [--input PATH|-] [--iterations N] [--format text|csv|json]
+       aoc generate --day N [--seed N] [--size N] [--output PATH]
+       aoc diff [--day N] [--part 1|2|all] [--seed N] [--cases N] [--size N]";
+
+/// What the runner has been asked to do
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Command {
+    /// Solve the puzzles and print the answers
+    Run,
+    /// Solve the puzzles and compare the answers against the known-correct ones
+    Verify,
+    /// Time parsing and solving each puzzle
+    Bench,
+    /// Write a random puzzle input
+    Generate,
+    /// Compare each solver against its reference implementation on generated inputs
+    Diff,
+}
+
+/// How results are written out
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Format {
+    Text,
+    Csv,
+    /// One JSON object per line
+    Json,
+}
+
+#[derive(Debug)]
+pub struct Args {
+    pub command: Command,
+    pub day: Option<u8>,
+    pub part: Option<Part>,
+    pub input: InputSource,
+    pub answers: InputSource,
+    pub iterations: usize,
+    pub format: Format,
+    pub seed: u64,
+    pub size: usize,
+    pub output: Option<PathBuf>,
+    pub cases: u64,
+}
+
+impl Args {
+    pub fn parse(args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut args = args.peekable();
+        let command = match args.peek().map(String::as_str) {
+            Some("run") => {
+                args.next();
+                Command::Run
+            }
+            Some("verify") => {
+                args.next();
+                Command::Verify
+            }
+            Some("bench") => {
+                args.next();
+                Command::Bench
+            }
+            Some("generate") => {
+                args.next();
+                Command::Generate
+            }
+            Some("diff") => {
+                args.next();
+                Command::Diff
+            }
+            _ => Command::Run,
+        };
+
+        let mut day = None;
+        let mut part = None;
+        let mut input = InputSource::Bundled;
+        let mut answers = InputSource::Bundled;
+        let mut iterations = 10;
+        let mut format = Format::Text;
+        let mut seed = 1;
+        let mut size = None;
+        let mut output = None;
+        let mut cases = 100;
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--day" => {
+                    let value = args.next().ok_or("--day needs a value")?;
+                    let value = value
+                        .parse()
+                        .map_err(|_| format!("invalid day {value:?}"))?;
+                    day = Some(value);
+                }
+                "--part" if command != Command::Generate => {
+                    let value = args.next().ok_or("--part needs a value")?;
+                    part = match value.as_str() {
+                        "1" => Some(Part::One),
+                        "2" => Some(Part::Two),
+                        "all" => None,
+                        _ => return Err(format!("invalid part {value:?}")),
+                    };
+                }
+                "--input" if !matches!(command, Command::Generate | Command::Diff) => {
+                    let value = args.next().ok_or("--input needs a value")?;
+                    input = InputSource::from_arg(Some(&value));
+                }
+                "--answers" if command == Command::Verify => {
+                    let value = args.next().ok_or("--answers needs a value")?;
+                    answers = InputSource::from_arg(Some(&value));
+                }
+                "--iterations" if command == Command::Bench => {
+                    let value = args.next().ok_or("--iterations needs a value")?;
+                    iterations = match value.parse() {
+                        Ok(0) | Err(_) => return Err(format!("invalid iterations {value:?}")),
+                        Ok(iterations) => iterations,
+                    };
+                }
+                "--format" if matches!(command, Command::Run | Command::Bench) => {
+                    let value = args.next().ok_or("--format needs a value")?;
+                    format = match value.as_str() {
+                        "text" => Format::Text,
+                        "csv" if command == Command::Bench => Format::Csv,
+                        "json" => Format::Json,
+                        _ => return Err(format!("invalid format {value:?}")),
+                    };
+                }
+                "--seed" if matches!(command, Command::Generate | Command::Diff) => {
+                    let value = args.next().ok_or("--seed needs a value")?;
+                    seed = value
+                        .parse()
+                        .map_err(|_| format!("invalid seed {value:?}"))?;
+                }
+                "--size" if matches!(command, Command::Generate | Command::Diff) => {
+                    let value = args.next().ok_or("--size needs a value")?;
+                    let value = value
+                        .parse()
+                        .map_err(|_| format!("invalid size {value:?}"))?;
+                    size = Some(value);
+                }
+                "--cases" if command == Command::Diff => {
+                    let value = args.next().ok_or("--cases needs a value")?;
+                    cases = value
+                        .parse()
+                        .map_err(|_| format!("invalid cases {value:?}"))?;
+                }
+                "--output" if command == Command::Generate => {
+                    let value = args.next().ok_or("--output needs a value")?;
+                    output = Some(PathBuf::from(value));
+                }
+                _ => return Err(format!("unexpected argument {arg:?}")),
+            }
+        }
+
+        if input != InputSource::Bundled && day.is_none() {
+            return Err("--input needs --day to say which puzzle it is for".to_string());
+        }
+        if command == Command::Generate && day.is_none() {
+            return Err("generate needs --day to say which puzzle to write".to_string());
+        }
+        if input == InputSource::Stdin && answers == InputSource::Stdin {
+            return Err("--input and --answers can't both be read from stdin".to_string());
+        }
+
+        Ok(Args {
+            command,
+            day,
+            part,
+            input,
+            answers,
+            iterations,
+            format,
+            seed,
+            // The reference solvers are slow, so comparing against them uses smaller inputs
+            size: size.unwrap_or(if command == Command::Diff { 20 } else { 100 }),
+            output,
+            cases,
+        })
+    }
+
+    pub fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(part) => vec![part],
+            None => Part::ALL.to_vec(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Args, String> {
+        Args::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let args = parse(&[]).unwrap();
+        assert_eq!(args.command, Command::Run);
+        assert_eq!(args.day, None);
+        assert_eq!(args.parts(), [Part::One, Part::Two]);
+        assert_eq!(args.input, InputSource::Bundled);
+
+        let args = parse(&["--day", "4", "--part", "2"]).unwrap();
+        assert_eq!(args.day, Some(4));
+        assert_eq!(args.parts(), [Part::Two]);
+
+        let args = parse(&["run", "--part", "all"]).unwrap();
+        assert_eq!(args.command, Command::Run);
+        assert_eq!(args.parts(), [Part::One, Part::Two]);
+
+        let args = parse(&["--day", "1", "--input", "-"]).unwrap();
+        assert_eq!(args.input, InputSource::Stdin);
+
+        let args = parse(&["--format", "json"]).unwrap();
+        assert_eq!(args.format, Format::Json);
+
+        assert!(parse(&["--format", "csv"]).is_err());
+        assert!(parse(&["verify", "--format", "json"]).is_err());
+        assert!(parse(&["--day"]).is_err());
+        assert!(parse(&["--day", "x"]).is_err());
+        assert!(parse(&["--part", "3"]).is_err());
+        assert!(parse(&["--input"]).is_err());
+        assert!(parse(&["--input", "x"]).is_err());
+        assert!(parse(&["--bogus"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_verify_args() {
+        let args = parse(&["verify"]).unwrap();
+        assert_eq!(args.command, Command::Verify);
+        assert_eq!(args.answers, InputSource::Bundled);
+
+        let args = parse(&["verify", "--answers", "-", "--day", "2"]).unwrap();
+        assert_eq!(args.answers, InputSource::Stdin);
+        assert_eq!(args.day, Some(2));
+
+        assert!(parse(&["--answers", "answers.txt"]).is_err());
+        assert!(parse(&["verify", "--day", "1", "--input", "-", "--answers", "-"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_bench_args() {
+        let args = parse(&["bench"]).unwrap();
+        assert_eq!(args.command, Command::Bench);
+        assert_eq!(args.iterations, 10);
+        assert_eq!(args.format, Format::Text);
+
+        let args = parse(&["bench", "--iterations", "3", "--format", "csv"]).unwrap();
+        assert_eq!(args.iterations, 3);
+        assert_eq!(args.format, Format::Csv);
+
+        assert!(parse(&["bench", "--iterations", "0"]).is_err());
+        let args = parse(&["bench", "--format", "json"]).unwrap();
+        assert_eq!(args.format, Format::Json);
+
+        assert!(parse(&["bench", "--format", "xml"]).is_err());
+        assert!(parse(&["--iterations", "3"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_generate_args() {
+        let args = parse(&["generate", "--day", "3"]).unwrap();
+        assert_eq!(args.command, Command::Generate);
+        assert_eq!(args.day, Some(3));
+        assert_eq!(args.seed, 1);
+        assert_eq!(args.size, 100);
+        assert_eq!(args.output, None);
+
+        let args = parse(&[
+            "generate", "--day", "3", "--seed", "42", "--size", "7", "--output", "out.txt",
+        ])
+        .unwrap();
+        assert_eq!(args.seed, 42);
+        assert_eq!(args.size, 7);
+        assert_eq!(args.output, Some(PathBuf::from("out.txt")));
+
+        assert!(parse(&["generate"]).is_err());
+        assert!(parse(&["generate", "--day", "3", "--part", "1"]).is_err());
+        assert!(parse(&["generate", "--day", "3", "--seed", "x"]).is_err());
+        assert!(parse(&["--seed", "1"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_diff_args() {
+        let args = parse(&["diff"]).unwrap();
+        assert_eq!(args.command, Command::Diff);
+        assert_eq!(args.day, None);
+        assert_eq!(args.cases, 100);
+        assert_eq!(args.size, 20);
+
+        let args = parse(&["diff", "--day", "2", "--seed", "9", "--cases", "5"]).unwrap();
+        assert_eq!(args.day, Some(2));
+        assert_eq!(args.seed, 9);
+        assert_eq!(args.cases, 5);
+
+        assert!(parse(&["diff", "--input", "x"]).is_err());
+        assert!(parse(&["--cases", "5"]).is_err());
+    }
+}
@@ -0,0 +1,217 @@
+use crate::json;
+use common::{Part, Solver};
+use std::{
+    fmt,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+/// The piece of a day that is being timed
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Stage {
+    Parse,
+    Part(Part),
+}
+
+impl Stage {
+    /// A name for the stage without spaces, for machine-readable output
+    pub fn key(self) -> &'static str {
+        match self {
+            Stage::Parse => "parse",
+            Stage::Part(Part::One) => "part1",
+            Stage::Part(Part::Two) => "part2",
+        }
+    }
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stage::Parse => f.write_str("parse"),
+            Stage::Part(part) => write!(f, "part {}", part.number()),
+        }
+    }
+}
+
+/// How long each iteration of a stage took, sorted from fastest to slowest
+#[derive(Debug)]
+pub struct Timings {
+    durations: Vec<Duration>,
+}
+
+impl Timings {
+    /// Run `f` `iterations` times, stopping at the first error
+    pub fn measure<T, E>(
+        iterations: usize,
+        mut f: impl FnMut() -> Result<T, E>,
+    ) -> Result<Self, E> {
+        assert!(iterations > 0);
+
+        let mut durations = Vec::with_capacity(iterations);
+        for _ in 0..iterations {
+            let start = Instant::now();
+            let value = f()?;
+            durations.push(start.elapsed());
+            black_box(value);
+        }
+        durations.sort();
+        Ok(Self::from_sorted(durations))
+    }
+
+    fn from_sorted(durations: Vec<Duration>) -> Self {
+        Self { durations }
+    }
+
+    pub fn iterations(&self) -> usize {
+        self.durations.len()
+    }
+
+    pub fn min(&self) -> Duration {
+        self.durations[0]
+    }
+
+    pub fn median(&self) -> Duration {
+        let len = self.durations.len();
+        if len % 2 == 1 {
+            self.durations[len / 2]
+        } else {
+            (self.durations[len / 2 - 1] + self.durations[len / 2]) / 2
+        }
+    }
+
+    pub fn max(&self) -> Duration {
+        self.durations[self.durations.len() - 1]
+    }
+}
+
+/// The timings for one stage of one day
+#[derive(Debug)]
+pub struct Measurement {
+    pub day: u8,
+    pub stage: Stage,
+    pub timings: Timings,
+}
+
+impl Measurement {
+    pub const CSV_HEADER: &str = "day,stage,iterations,min_ns,median_ns,max_ns";
+
+    pub fn to_csv(&self) -> String {
+        format!(
+            "{},{},{},{},{},{}",
+            self.day,
+            self.stage.key(),
+            self.timings.iterations(),
+            self.timings.min().as_nanos(),
+            self.timings.median().as_nanos(),
+            self.timings.max().as_nanos(),
+        )
+    }
+
+    pub fn to_json(&self) -> String {
+        json::Object::new()
+            .number("day", self.day)
+            .string("stage", self.stage.key())
+            .number("iterations", self.timings.iterations() as u64)
+            .number("min_ns", self.timings.min().as_nanos())
+            .number("median_ns", self.timings.median().as_nanos())
+            .number("max_ns", self.timings.max().as_nanos())
+            .finish()
+    }
+
+    pub fn to_text(&self) -> String {
+        format!(
+            "day {:02} {:<6}  min {:>12.3?}  median {:>12.3?}  max {:>12.3?}",
+            self.day,
+            self.stage.to_string(),
+            self.timings.min(),
+            self.timings.median(),
+            self.timings.max(),
+        )
+    }
+}
+
+/// Time parsing, then each of `parts` on the parsed input, for a single day
+pub fn bench(
+    solver: &dyn Solver,
+    input: &str,
+    parts: &[Part],
+    iterations: usize,
+) -> Result<Vec<Measurement>, common::ParseError> {
+    let mut measurements = Vec::new();
+
+    let timings = Timings::measure(iterations, || solver.parse(input))?;
+    measurements.push(Measurement {
+        day: solver.day(),
+        stage: Stage::Parse,
+        timings,
+    });
+
+    let parsed = solver.parse(input)?;
+    for &part in parts {
+        let timings = Timings::measure(iterations, || parsed.solve(part))?;
+        measurements.push(Measurement {
+            day: solver.day(),
+            stage: Stage::Part(part),
+            timings,
+        });
+    }
+
+    Ok(measurements)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timings(millis: &[u64]) -> Timings {
+        Timings::from_sorted(millis.iter().copied().map(Duration::from_millis).collect())
+    }
+
+    #[test]
+    fn test_timings() {
+        let t = timings(&[1, 2, 10]);
+        assert_eq!(t.min(), Duration::from_millis(1));
+        assert_eq!(t.median(), Duration::from_millis(2));
+        assert_eq!(t.max(), Duration::from_millis(10));
+
+        let t = timings(&[1, 2, 4, 10]);
+        assert_eq!(t.median(), Duration::from_millis(3));
+
+        let t = Timings::measure(5, || Ok::<_, ()>(())).unwrap();
+        assert_eq!(t.iterations(), 5);
+        assert!(t.min() <= t.median() && t.median() <= t.max());
+
+        let mut calls = 0;
+        let err = Timings::measure(5, || {
+            calls += 1;
+            if calls == 3 { Err("boom") } else { Ok(()) }
+        });
+        assert_eq!(err.unwrap_err(), "boom");
+        assert_eq!(calls, 3);
+    }
+
+    #[test]
+    fn test_csv() {
+        let measurement = Measurement {
+            day: 4,
+            stage: Stage::Part(Part::Two),
+            timings: timings(&[1, 2, 10]),
+        };
+        assert_eq!(measurement.to_csv(), "4,part2,3,1000000,2000000,10000000");
+        assert_eq!(
+            measurement.to_json(),
+            r#"{"day":4,"stage":"part2","iterations":3,"min_ns":1000000,"median_ns":2000000,"max_ns":10000000}"#
+        );
+    }
+
+    #[test]
+    fn test_bench() {
+        let measurements = bench(&day_01::Day01, day_01::INPUT, &Part::ALL, 2).unwrap();
+        let stages = measurements.iter().map(|m| m.stage).collect::<Vec<_>>();
+        assert_eq!(
+            stages,
+            [Stage::Parse, Stage::Part(Part::One), Stage::Part(Part::Two)]
+        );
+        assert!(bench(&day_01::Day01, "X1", &Part::ALL, 2).is_err());
+    }
+}
@@ -0,0 +1,76 @@
+use std::fmt::Write;
+
+/// Builds a single-line JSON object, one field at a time
+#[derive(Debug, Default)]
+pub struct Object {
+    out: String,
+}
+
+impl Object {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn string(mut self, key: &str, value: &str) -> Self {
+        self.key(key);
+        push_string(&mut self.out, value);
+        self
+    }
+
+    pub fn number(mut self, key: &str, value: impl Into<u128>) -> Self {
+        self.key(key);
+        write!(self.out, "{}", value.into()).unwrap();
+        self
+    }
+
+    fn key(&mut self, key: &str) {
+        self.out.push(if self.out.is_empty() { '{' } else { ',' });
+        push_string(&mut self.out, key);
+        self.out.push(':');
+    }
+
+    pub fn finish(mut self) -> String {
+        if self.out.is_empty() {
+            self.out.push('{');
+        }
+        self.out.push('}');
+        self.out
+    }
+}
+
+fn push_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_object() {
+        assert_eq!(Object::new().finish(), "{}");
+
+        let object = Object::new()
+            .number("day", 1_u8)
+            .string("answer", "1154")
+            .finish();
+        assert_eq!(object, r#"{"day":1,"answer":"1154"}"#);
+
+        let object = Object::new()
+            .string("error", "line 1: \"X\"\n\u{1}")
+            .finish();
+        assert_eq!(object, r#"{"error":"line 1: \"X\"\n\u0001"}"#);
+    }
+}
@@ -0,0 +1,314 @@
+use crate::{
+    answers::{ANSWERS, Answers},
+    args::{Args, Command, Format, USAGE},
+    bench::Measurement,
+};
+use common::{Answer, ParseError, Part, Solver, differential};
+use std::{
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+mod answers;
+mod args;
+mod bench;
+mod json;
+
+const SOLVERS: [&dyn Solver; 6] = [
+    &day_01::Day01,
+    &day_02::Day02,
+    &day_03::Day03,
+    &day_04::Day04,
+    &day_05::Day05,
+    &day_06::Day06,
+];
+
+fn main() -> ExitCode {
+    let args = match Args::parse(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("{err}");
+            eprintln!("{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let solvers = match args.day {
+        Some(day) => match find_solver(day) {
+            Some(solver) => vec![solver],
+            None => {
+                eprintln!("no solver for day {day}");
+                return ExitCode::from(2);
+            }
+        },
+        None => SOLVERS.to_vec(),
+    };
+
+    match args.command {
+        Command::Run => run(&args, &solvers),
+        Command::Verify => verify(&args, &solvers),
+        Command::Bench => bench(&args, &solvers),
+        Command::Generate => generate(&args, solvers[0]),
+        Command::Diff => diff(&args, &solvers),
+    }
+}
+
+fn find_solver(day: u8) -> Option<&'static dyn Solver> {
+    SOLVERS.iter().copied().find(|solver| solver.day() == day)
+}
+
+fn run(args: &Args, solvers: &[&dyn Solver]) -> ExitCode {
+    for solver in solvers {
+        let input = match args.input.load(solver.input()) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("{err}");
+                return ExitCode::FAILURE;
+            }
+        };
+        for part in args.parts() {
+            let start = Instant::now();
+            let result = solver.solve(part, &input);
+            let elapsed = start.elapsed();
+
+            if args.format == Format::Json {
+                println!("{}", run_json(solver.day(), part, &result, elapsed));
+            }
+            match result {
+                Ok(answer) => {
+                    if args.format == Format::Text {
+                        println!("day {:02} part {}: {answer}", solver.day(), part.number());
+                    }
+                }
+                Err(err) => {
+                    eprintln!("day {:02} part {}: {err}", solver.day(), part.number());
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+    }
+
+    ExitCode::SUCCESS
+}
+
+/// Describe the result of solving one part as a JSON object
+///
+/// The answer is written as a string because it may be too big for a JSON number to hold exactly.
+fn run_json(day: u8, part: Part, result: &Result<Answer, ParseError>, elapsed: Duration) -> String {
+    let object = json::Object::new()
+        .number("day", day)
+        .number("part", part.number());
+    let object = match result {
+        Ok(answer) => object
+            .string("answer", &answer.to_string())
+            .string("answer_type", answer.type_name()),
+        Err(err) => object.string("error", &err.to_string()),
+    };
+    object.number("elapsed_ns", elapsed.as_nanos()).finish()
+}
+
+fn verify(args: &Args, solvers: &[&dyn Solver]) -> ExitCode {
+    let answers = match args.answers.load(ANSWERS) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let answers = match Answers::parse(&answers) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("{}: {err}", args.answers);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut passed = 0;
+    let mut failed = 0;
+    let mut unknown = 0;
+
+    for solver in solvers {
+        let input = match args.input.load(solver.input()) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("{err}");
+                return ExitCode::FAILURE;
+            }
+        };
+        for part in args.parts() {
+            let label = format!("day {:02} part {}", solver.day(), part.number());
+            let expected = answers.get(solver.day(), part);
+            match (solver.solve(part, &input), expected) {
+                (Ok(answer), Some(expected)) if answer.to_string() == expected => {
+                    println!("{label}: pass ({answer})");
+                    passed += 1;
+                }
+                (Ok(answer), Some(expected)) => {
+                    println!("{label}: FAIL (expected {expected}, got {answer})");
+                    failed += 1;
+                }
+                (Ok(answer), None) => {
+                    println!("{label}: unknown (got {answer})");
+                    unknown += 1;
+                }
+                (Err(err), _) => {
+                    println!("{label}: FAIL ({err})");
+                    failed += 1;
+                }
+            }
+        }
+    }
+
+    println!("{passed} passed, {failed} failed, {unknown} unknown");
+
+    if failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn bench(args: &Args, solvers: &[&dyn Solver]) -> ExitCode {
+    if args.format == Format::Csv {
+        println!("{}", Measurement::CSV_HEADER);
+    }
+
+    for solver in solvers {
+        let input = match args.input.load(solver.input()) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("{err}");
+                return ExitCode::FAILURE;
+            }
+        };
+        let measurements = match bench::bench(*solver, &input, &args.parts(), args.iterations) {
+            Ok(measurements) => measurements,
+            Err(err) => {
+                eprintln!("day {:02}: {err}", solver.day());
+                return ExitCode::FAILURE;
+            }
+        };
+        for measurement in measurements {
+            match args.format {
+                Format::Text => println!("{}", measurement.to_text()),
+                Format::Csv => println!("{}", measurement.to_csv()),
+                Format::Json => println!("{}", measurement.to_json()),
+            }
+        }
+    }
+
+    ExitCode::SUCCESS
+}
+
+fn generate(args: &Args, solver: &dyn Solver) -> ExitCode {
+    let input = solver.generate(args.seed, args.size);
+    match &args.output {
+        Some(path) => {
+            if let Err(err) = std::fs::write(path, input) {
+                eprintln!("could not write {}: {err}", path.display());
+                return ExitCode::FAILURE;
+            }
+        }
+        None => print!("{input}"),
+    }
+
+    ExitCode::SUCCESS
+}
+
+fn diff(args: &Args, solvers: &[&dyn Solver]) -> ExitCode {
+    let mut failed = false;
+    for solver in solvers {
+        for part in args.parts() {
+            let label = format!("day {:02} part {}", solver.day(), part.number());
+            let seeds = args.seed..args.seed.saturating_add(args.cases);
+            let Some(disagreement) = differential::check(*solver, part, seeds, args.size) else {
+                println!("{label}: ok ({} cases)", args.cases);
+                continue;
+            };
+
+            failed = true;
+            let show = |result: &Result<Answer, ParseError>| match result {
+                Ok(answer) => answer.to_string(),
+                Err(err) => err.to_string(),
+            };
+            println!(
+                "{label}: disagreement on the input from seed {}",
+                disagreement.seed
+            );
+            println!("  solve:     {}", show(&disagreement.actual));
+            println!("  reference: {}", show(&disagreement.expected));
+            println!("  minimized input:");
+            for line in disagreement.input.lines() {
+                println!("    {line}");
+            }
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_day_has_a_solver() {
+        for day in 1..=6 {
+            let solver = find_solver(day).unwrap();
+            assert_eq!(solver.day(), day);
+        }
+        assert!(find_solver(7).is_none());
+    }
+
+    #[test]
+    fn test_run_json() {
+        let result = Ok(Answer::from(1154_u16));
+        let json = run_json(1, Part::One, &result, Duration::from_micros(5));
+        assert_eq!(
+            json,
+            r#"{"day":1,"part":1,"answer":"1154","answer_type":"u16","elapsed_ns":5000}"#
+        );
+
+        let result = Err(ParseError::new("X4", "expected L or R").at(2, 1));
+        let json = run_json(1, Part::Two, &result, Duration::from_nanos(7));
+        assert_eq!(
+            json,
+            r#"{"day":1,"part":2,"error":"line 2, column 1: expected L or R (found \"X4\")","elapsed_ns":7}"#
+        );
+    }
+
+    #[test]
+    fn test_generated_inputs_are_solvable() {
+        for solver in SOLVERS {
+            for size in [0, 1, 20] {
+                let input = solver.generate(5, size);
+                assert_eq!(input, solver.generate(5, size));
+                let parsed = solver.parse(&input).unwrap();
+                for part in Part::ALL {
+                    let answer = solver.solve(part, &input).unwrap();
+                    assert_eq!(parsed.solve(part), Ok(answer));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_bundled_answers_are_correct() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        for solver in SOLVERS {
+            let parsed = solver.parse(solver.input()).unwrap();
+            for part in Part::ALL {
+                let answer = solver.solve(part, solver.input()).unwrap();
+                assert_eq!(
+                    Some(answer.to_string().as_str()),
+                    answers.get(solver.day(), part)
+                );
+                assert_eq!(parsed.solve(part), Ok(answer));
+            }
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use std::fmt;
+
+/// The answer to one part of a puzzle
+///
+/// Days don't agree on what integer type their answers come in, so this keeps track of which one
+/// was produced.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Answer {
+    U16(u16),
+    U64(u64),
+    Usize(usize),
+    U128(u128),
+}
+
+impl Answer {
+    /// The name of the integer type the answer was produced as
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Answer::U16(_) => "u16",
+            Answer::U64(_) => "u64",
+            Answer::Usize(_) => "usize",
+            Answer::U128(_) => "u128",
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::U16(v) => v.fmt(f),
+            Answer::U64(v) => v.fmt(f),
+            Answer::Usize(v) => v.fmt(f),
+            Answer::U128(v) => v.fmt(f),
+        }
+    }
+}
+
+impl From<u16> for Answer {
+    fn from(value: u16) -> Self {
+        Answer::U16(value)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::U64(value)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Usize(value)
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        Answer::U128(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(3_u16).to_string(), "3");
+        assert_eq!(Answer::from(14_u64).to_string(), "14");
+        assert_eq!(Answer::from(1227775554_usize).to_string(), "1227775554");
+        assert_eq!(Answer::from(u128::MAX).to_string(), u128::MAX.to_string());
+    }
+
+    #[test]
+    fn test_type_name() {
+        assert_eq!(Answer::from(3_u16).type_name(), "u16");
+        assert_eq!(Answer::from(14_u64).type_name(), "u64");
+        assert_eq!(Answer::from(1227775554_usize).type_name(), "usize");
+        assert_eq!(Answer::from(4174379265_u128).type_name(), "u128");
+    }
+}
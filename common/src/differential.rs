@@ -0,0 +1,145 @@
+//! Checking a solver against its reference implementation on generated inputs
+
+use crate::{Answer, ParseError, Part, Solver};
+
+/// An input where [`Solver::solve`] and [`Solver::reference`] don't agree
+#[derive(Clone, Debug)]
+pub struct Disagreement {
+    /// The seed the input was generated from
+    pub seed: u64,
+    pub part: Part,
+    /// The smallest input found that still disagrees
+    pub input: String,
+    pub actual: Result<Answer, ParseError>,
+    pub expected: Result<Answer, ParseError>,
+}
+
+/// Whether two results disagree
+///
+/// Two errors are treated as agreeing, since the two implementations aren't expected to describe
+/// the problem in the same way.
+pub fn disagree(a: &Result<Answer, ParseError>, b: &Result<Answer, ParseError>) -> bool {
+    match (a, b) {
+        (Ok(a), Ok(b)) => a != b,
+        (Err(_), Err(_)) => false,
+        _ => true,
+    }
+}
+
+/// Check `part` on the inputs generated from each of `seeds`, returning the first disagreement
+///
+/// The input is minimized before it is returned.
+pub fn check(
+    solver: &dyn Solver,
+    part: Part,
+    seeds: impl IntoIterator<Item = u64>,
+    size: usize,
+) -> Option<Disagreement> {
+    let disagrees =
+        |input: &str| disagree(&solver.solve(part, input), &solver.reference(part, input));
+
+    for seed in seeds {
+        let input = solver.generate(seed, size);
+        if disagrees(&input) {
+            let input = minimize(&input, |input| disagrees(input));
+            return Some(Disagreement {
+                seed,
+                part,
+                actual: solver.solve(part, &input),
+                expected: solver.reference(part, &input),
+                input,
+            });
+        }
+    }
+    None
+}
+
+/// Remove as many lines from `input` as possible while `fails` still holds, then as many of the
+/// comma separated items within each line that is left
+///
+/// This tries removing large chunks first, then smaller and smaller ones, down to single lines or
+/// items. `fails` must hold for `input` itself.
+pub fn minimize(input: &str, fails: impl Fn(&str) -> bool) -> String {
+    let join = |lines: &[String]| {
+        let mut out = lines.join("\n");
+        out.push('\n');
+        out
+    };
+
+    let lines = input.lines().map(str::to_string).collect::<Vec<_>>();
+    let mut lines = remove_chunks(lines, |lines| fails(&join(lines)));
+
+    for i in 0..lines.len() {
+        let items = lines[i].split(',').map(str::to_string).collect::<Vec<_>>();
+        let items = remove_chunks(items, |items| {
+            let mut candidate = lines.clone();
+            candidate[i] = items.join(",");
+            fails(&join(&candidate))
+        });
+        lines[i] = items.join(",");
+    }
+
+    join(&lines)
+}
+
+/// Remove as many of `items` as possible while `fails` still holds, always leaving at least one
+fn remove_chunks(mut items: Vec<String>, fails: impl Fn(&[String]) -> bool) -> Vec<String> {
+    let mut chunk = items.len().div_ceil(2).max(1);
+    loop {
+        let mut removed_any = false;
+        let mut start = 0;
+        while start < items.len() && items.len() > 1 {
+            let end = (start + chunk).min(items.len());
+            let candidate = [&items[..start], &items[end..]].concat();
+            if fails(&candidate) {
+                items = candidate;
+                removed_any = true;
+            } else {
+                start = end;
+            }
+        }
+
+        if chunk == 1 && !removed_any {
+            break;
+        }
+        if !removed_any {
+            chunk = chunk.div_ceil(2);
+        }
+    }
+    items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_disagree() {
+        let err = || Err(ParseError::new("x", "bad"));
+        assert!(!disagree(&Ok(Answer::U16(1)), &Ok(Answer::U16(1))));
+        assert!(disagree(&Ok(Answer::U16(1)), &Ok(Answer::U16(2))));
+        assert!(!disagree(&err(), &err()));
+        assert!(disagree(&Ok(Answer::U16(1)), &err()));
+    }
+
+    #[test]
+    fn test_minimize() {
+        let input = (1..=20).map(|n| format!("{n}\n")).collect::<String>();
+        let fails = |input: &str| input.lines().any(|line| line == "7");
+        assert_eq!(minimize(&input, fails), "7\n");
+
+        let fails = |input: &str| {
+            let lines = input.lines().collect::<Vec<_>>();
+            lines.contains(&"3") && lines.contains(&"15")
+        };
+        assert_eq!(minimize(&input, fails), "3\n15\n");
+
+        // A single line of comma separated items shrinks too
+        let input = (1..=20)
+            .map(|n| n.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        let fails = |input: &str| input.trim().split(',').any(|item| item == "12");
+        assert_eq!(minimize(&input, fails), "12\n");
+    }
+}
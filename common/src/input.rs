@@ -0,0 +1,182 @@
+use std::{
+    borrow::Cow,
+    fmt,
+    fs::{self, File},
+    io::{self, BufRead, BufReader, Read},
+    path::PathBuf,
+};
+
+/// Where a day's puzzle input comes from
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum InputSource {
+    /// The `input.txt` that was compiled into the binary
+    Bundled,
+    /// Whatever is piped in on standard input
+    Stdin,
+    /// A file on disk
+    Path(PathBuf),
+}
+
+impl InputSource {
+    /// Interpret a command line argument: nothing means the bundled input, `-` means stdin, and
+    /// anything else is a path
+    pub fn from_arg(arg: Option<&str>) -> Self {
+        match arg {
+            None => InputSource::Bundled,
+            Some("-") => InputSource::Stdin,
+            Some(path) => InputSource::Path(PathBuf::from(path)),
+        }
+    }
+
+    /// Read the input, falling back to `bundled` for [`InputSource::Bundled`]
+    pub fn load(&self, bundled: &'static str) -> Result<Cow<'static, str>, InputError> {
+        match self {
+            InputSource::Bundled => Ok(Cow::Borrowed(bundled)),
+            InputSource::Stdin => {
+                let mut bytes = Vec::new();
+                io::stdin()
+                    .read_to_end(&mut bytes)
+                    .map_err(|err| InputError::Io(self.clone(), err))?;
+                self.decode(bytes).map(Cow::Owned)
+            }
+            InputSource::Path(path) => {
+                let bytes = fs::read(path).map_err(|err| self.error(err))?;
+                self.decode(bytes).map(Cow::Owned)
+            }
+        }
+    }
+
+    /// Open the input to be read a piece at a time, falling back to `bundled` for
+    /// [`InputSource::Bundled`]
+    ///
+    /// Nothing checks that the input is UTF-8; that's up to whatever reads it.
+    pub fn open(&self, bundled: &'static str) -> Result<Box<dyn BufRead>, InputError> {
+        match self {
+            InputSource::Bundled => Ok(Box::new(bundled.as_bytes())),
+            InputSource::Stdin => Ok(Box::new(io::stdin().lock())),
+            InputSource::Path(path) => {
+                let file = File::open(path).map_err(|err| self.error(err))?;
+                Ok(Box::new(BufReader::new(file)))
+            }
+        }
+    }
+
+    /// Describe a failure to read this input
+    pub fn error(&self, err: io::Error) -> InputError {
+        match (self, err.kind()) {
+            (InputSource::Path(path), io::ErrorKind::NotFound) => {
+                InputError::NotFound(path.clone())
+            }
+            _ => InputError::Io(self.clone(), err),
+        }
+    }
+
+    fn decode(&self, bytes: Vec<u8>) -> Result<String, InputError> {
+        String::from_utf8(bytes).map_err(|err| InputError::NotUtf8 {
+            source: self.clone(),
+            offset: err.utf8_error().valid_up_to(),
+        })
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Bundled => f.write_str("bundled input"),
+            InputSource::Stdin => f.write_str("standard input"),
+            InputSource::Path(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// Load the input named by the first command line argument of a day's binary
+pub fn from_args(bundled: &'static str) -> Result<Cow<'static, str>, InputError> {
+    let arg = std::env::args().nth(1);
+    InputSource::from_arg(arg.as_deref()).load(bundled)
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound(PathBuf),
+    NotUtf8 { source: InputSource, offset: usize },
+    Io(InputSource, io::Error),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::NotFound(path) => {
+                write!(f, "input file {} does not exist", path.display())
+            }
+            InputError::NotUtf8 { source, offset } => {
+                write!(f, "{source} is not valid UTF-8 (at byte {offset})")
+            }
+            InputError::Io(source, err) => write!(f, "could not read {source}: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_arg() {
+        assert_eq!(InputSource::from_arg(None), InputSource::Bundled);
+        assert_eq!(InputSource::from_arg(Some("-")), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from_arg(Some("input.txt")),
+            InputSource::Path(PathBuf::from("input.txt"))
+        );
+    }
+
+    #[test]
+    fn test_load() {
+        let loaded = InputSource::Bundled.load("bundled").unwrap();
+        assert_eq!(loaded, "bundled");
+
+        let dir = std::env::temp_dir().join(format!("aoc-input-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let path = dir.join("input.txt");
+        fs::write(&path, "L68\nR48\n").unwrap();
+        let loaded = InputSource::Path(path).load("bundled").unwrap();
+        assert_eq!(loaded, "L68\nR48\n");
+
+        let mut opened = String::new();
+        let source = InputSource::Path(dir.join("input.txt"));
+        source
+            .open("bundled")
+            .unwrap()
+            .read_to_string(&mut opened)
+            .unwrap();
+        assert_eq!(opened, "L68\nR48\n");
+
+        let mut opened = String::new();
+        let source = InputSource::Bundled;
+        source
+            .open("bundled")
+            .unwrap()
+            .read_to_string(&mut opened)
+            .unwrap();
+        assert_eq!(opened, "bundled");
+
+        let path = dir.join("missing.txt");
+        let err = InputSource::Path(path.clone()).load("bundled").unwrap_err();
+        assert!(matches!(err, InputError::NotFound(p) if p == path));
+        let err = InputSource::Path(path.clone())
+            .open("bundled")
+            .err()
+            .unwrap();
+        assert!(matches!(err, InputError::NotFound(p) if p == path));
+
+        let path = dir.join("binary.txt");
+        fs::write(&path, b"L68\n\xffR48").unwrap();
+        let err = InputSource::Path(path).load("bundled").unwrap_err();
+        assert!(matches!(err, InputError::NotUtf8 { offset: 4, .. }));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,12 @@
+pub use answer::Answer;
+pub use input::{InputError, InputSource};
+pub use parse_error::ParseError;
+pub use rng::Rng;
+pub use solver::{Parsed, Part, Solver};
+
+mod answer;
+pub mod differential;
+pub mod input;
+mod parse_error;
+mod rng;
+mod solver;
@@ -0,0 +1,127 @@
+use std::fmt;
+
+/// Something in a puzzle input that couldn't be understood
+///
+/// Lines and columns count from 1. Parsers that only see a piece of the input report positions
+/// relative to that piece, and the caller moves the error to where the piece sits in the whole
+/// input with [`ParseError::within`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    line: usize,
+    column: usize,
+    text: String,
+    reason: String,
+}
+
+impl ParseError {
+    /// An error at the very start of `text`
+    pub fn new(text: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self {
+            line: 1,
+            column: 1,
+            text: text.into(),
+            reason: reason.into(),
+        }
+    }
+
+    /// Move the error to a line and column
+    pub fn at(mut self, line: usize, column: usize) -> Self {
+        self.line = line;
+        self.column = column;
+        self
+    }
+
+    /// Move the error so that it is relative to `input` instead of `part`
+    ///
+    /// `part` must be a slice of `input`, as produced by `lines`, `split`, `trim` and friends.
+    pub fn within(self, input: &str, part: &str) -> Self {
+        let offset = (part.as_ptr() as usize).wrapping_sub(input.as_ptr() as usize);
+        assert!(
+            offset <= input.len(),
+            "part is not a slice of the input it is being located within"
+        );
+
+        let before = &input[..offset];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+        let column = before[line_start..].chars().count() + 1;
+
+        if self.line == 1 {
+            let column = column + self.column - 1;
+            self.at(line, column)
+        } else {
+            let line = line + self.line - 1;
+            let column = self.column;
+            self.at(line, column)
+        }
+    }
+
+    /// The line the problem is on
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// The column the problem starts at
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// The text that couldn't be understood
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// What was wrong with it
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {} (found {:?})",
+            self.line, self.column, self.reason, self.text
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_within() {
+        let input = "L68\nL30\nX48\n";
+        let line = input.lines().nth(2).unwrap();
+        let err = ParseError::new(line, "expected L or R").within(input, line);
+        assert_eq!(err.line(), 3);
+        assert_eq!(err.column(), 1);
+        assert_eq!(err.text(), "X48");
+        assert_eq!(err.reason(), "expected L or R");
+
+        let input = "11-22, 95-1x5";
+        let range = input.split(',').nth(1).unwrap().trim();
+        let err = ParseError::new("1x5", "invalid number")
+            .at(1, 4)
+            .within(input, range);
+        assert_eq!((err.line(), err.column()), (1, 11));
+
+        let input = "3-5\n10-14\n\n1\n5\n";
+        let ids = &input[11..];
+        let err = ParseError::new("5", "unknown").at(2, 1).within(input, ids);
+        assert_eq!((err.line(), err.column()), (5, 1));
+    }
+
+    #[test]
+    fn test_display() {
+        let err = ParseError::new("X48", "expected L or R").at(3, 1);
+        assert_eq!(
+            err.to_string(),
+            r#"line 3, column 1: expected L or R (found "X48")"#
+        );
+    }
+}
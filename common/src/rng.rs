@@ -0,0 +1,86 @@
+/// A small, seedable random number generator
+///
+/// This is SplitMix64: it is not suitable for anything cryptographic, but it is fast, has no
+/// dependencies, and the same seed always produces the same sequence on every platform, which is
+/// what generated puzzle inputs need.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number from `low` to `high`, inclusive
+    pub fn range(&mut self, low: u64, high: u64) -> u64 {
+        assert!(low <= high);
+        let span = high - low;
+        if span == u64::MAX {
+            return self.next_u64();
+        }
+        // Multiply-shift rather than modulo; the bias is far too small to matter here
+        let offset = ((self.next_u64() as u128 * (span as u128 + 1)) >> 64) as u64;
+        low + offset
+    }
+
+    /// An index into a collection of `len` items
+    pub fn below(&mut self, len: usize) -> usize {
+        assert!(len > 0);
+        self.range(0, len as u64 - 1) as usize
+    }
+
+    /// True `percent` percent of the time
+    pub fn chance(&mut self, percent: u8) -> bool {
+        self.range(0, 99) < percent as u64
+    }
+
+    /// Pick one of `items`
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deterministic() {
+        let a = (0..5).map(|_| Rng::new(7).next_u64()).collect::<Vec<_>>();
+        assert!(a.iter().all(|&v| v == a[0]));
+
+        let mut a = Rng::new(7);
+        let mut b = Rng::new(7);
+        let mut c = Rng::new(8);
+        let a = (0..5).map(|_| a.next_u64()).collect::<Vec<_>>();
+        let b = (0..5).map(|_| b.next_u64()).collect::<Vec<_>>();
+        let c = (0..5).map(|_| c.next_u64()).collect::<Vec<_>>();
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn test_range() {
+        let mut rng = Rng::new(1);
+        let mut seen = [false; 6];
+        for _ in 0..1000 {
+            let v = rng.range(10, 15);
+            assert!((10..=15).contains(&v));
+            seen[(v - 10) as usize] = true;
+        }
+        assert!(seen.iter().all(|&s| s));
+
+        assert_eq!(rng.range(3, 3), 3);
+        rng.range(0, u64::MAX);
+    }
+}
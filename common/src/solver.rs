@@ -0,0 +1,72 @@
+use crate::{Answer, ParseError};
+
+/// One of the two parts of a day's puzzle
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    /// The part as it is written in the puzzle: 1 or 2
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+/// A day's input once it has been parsed, ready to solve either part without parsing it again
+pub trait Parsed {
+    fn part_one(&self) -> Result<Answer, ParseError>;
+
+    fn part_two(&self) -> Result<Answer, ParseError>;
+
+    /// Solve the requested part
+    fn solve(&self, part: Part) -> Result<Answer, ParseError> {
+        match part {
+            Part::One => self.part_one(),
+            Part::Two => self.part_two(),
+        }
+    }
+}
+
+/// A solution to a single day
+///
+/// Every day implements this so that the runner can treat them all the same way.
+pub trait Solver {
+    /// The day of the month this solves
+    fn day(&self) -> u8;
+
+    /// The puzzle input that was bundled into the binary
+    fn input(&self) -> &'static str;
+
+    /// Parse the input without solving anything, so that parsing and each part can be timed on
+    /// their own
+    fn parse<'a>(&self, input: &'a str) -> Result<Box<dyn Parsed + 'a>, ParseError>;
+
+    fn part_one(&self, input: &str) -> Result<Answer, ParseError>;
+
+    fn part_two(&self, input: &str) -> Result<Answer, ParseError>;
+
+    /// Write a valid puzzle input from a seed, roughly `size` items big
+    ///
+    /// The same seed and size always produce the same input.
+    fn generate(&self, seed: u64, size: usize) -> String;
+
+    /// Solve the requested part the slow, obvious way
+    ///
+    /// This is deliberately naive so that it can be trusted to check [`Solver::solve`] against.
+    fn reference(&self, part: Part, input: &str) -> Result<Answer, ParseError>;
+
+    /// Solve the requested part
+    fn solve(&self, part: Part, input: &str) -> Result<Answer, ParseError> {
+        match part {
+            Part::One => self.part_one(input),
+            Part::Two => self.part_two(input),
+        }
+    }
+}
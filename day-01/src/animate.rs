@@ -0,0 +1,213 @@
+//! Drawing the dial in a terminal, one click at a time
+
+use crate::Turn;
+use std::f64::consts::TAU;
+
+/// The dial at one click of one turn
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Frame {
+    /// Which turn this is, counting from 1
+    pub step: usize,
+    /// The turn being made
+    pub turn: Turn,
+    /// How many clicks of the turn have been made so far
+    pub click: u64,
+    /// The position the dial is pointing at
+    pub position: u16,
+    /// Whether this click took the dial past zero
+    pub passed_zero: bool,
+    /// Whether this is the last click of the turn and it left the dial on zero
+    pub landed_on_zero: bool,
+    /// How many times the dial has passed zero so far, over all of the turns
+    pub crossings: u64,
+}
+
+/// Every frame of turning a dial of `positions` positions from `start` with each of `turns`
+///
+/// A dial passes zero the same way a [`Lock`](crate::Lock) counts it: turning left from 0, or
+/// turning right onto 0. A turn of zero clicks still gets a single frame.
+pub fn frames(
+    positions: u16,
+    start: u16,
+    turns: impl IntoIterator<Item = Turn>,
+) -> impl Iterator<Item = Frame> {
+    let mut position = start;
+    let mut crossings = 0;
+    turns
+        .into_iter()
+        .enumerate()
+        .flat_map(move |(i, turn)| {
+            let (Turn::Left(clicks) | Turn::Right(clicks)) = turn;
+            (0..clicks.max(1)).map(move |click| (i + 1, turn, click, clicks))
+        })
+        .map(move |(step, turn, click, clicks)| {
+            let mut passed_zero = false;
+            if clicks > 0 {
+                match turn {
+                    Turn::Left(_) => {
+                        passed_zero = position == 0;
+                        position = position.checked_sub(1).unwrap_or(positions - 1);
+                    }
+                    Turn::Right(_) => {
+                        position = (position + 1) % positions;
+                        passed_zero = position == 0;
+                    }
+                }
+            }
+            crossings += passed_zero as u64;
+            let last = click + 1 >= clicks;
+            Frame {
+                step,
+                turn,
+                click: if clicks == 0 { 0 } else { click + 1 },
+                position,
+                passed_zero,
+                landed_on_zero: last && position == 0,
+                crossings,
+            }
+        })
+}
+
+const RADIUS: usize = 8;
+const BOLD: &str = "\x1b[1m";
+const REVERSE: &str = "\x1b[7m";
+const RESET: &str = "\x1b[0m";
+
+/// Draw a frame as a ring of ticks with the pointer on it, and a line about the turn underneath
+///
+/// Zero is at the top and the numbers go up clockwise. Passing or landing on zero is picked out with
+/// terminal colours.
+pub fn render(frame: &Frame, positions: u16) -> String {
+    // Terminal cells are about twice as tall as they are wide
+    let (width, height) = (RADIUS * 4 + 1, RADIUS * 2 + 1);
+    let mut grid = vec![vec![' '; width]; height];
+    let cell = |position: u16| {
+        let angle = TAU * position as f64 / positions as f64;
+        let x = (RADIUS * 2) as f64 * (1.0 + angle.sin());
+        let y = RADIUS as f64 * (1.0 - angle.cos());
+        (x.round() as usize, y.round() as usize)
+    };
+
+    // Too many ticks would run together, so big dials only get some of them
+    let every = (positions as usize).div_ceil(48).max(1);
+    for position in (0..positions).step_by(every) {
+        let (x, y) = cell(position);
+        grid[y][x] = '.';
+    }
+    let (x, y) = cell(0);
+    grid[y][x] = '0';
+
+    let label = frame.position.to_string();
+    let start = (width - label.len()) / 2;
+    for (i, c) in label.chars().enumerate() {
+        grid[RADIUS][start + i] = c;
+    }
+
+    let (pointer_x, pointer_y) = cell(frame.position);
+    let mut out = String::new();
+    for (y, row) in grid.iter().enumerate() {
+        let mut line = String::new();
+        for (x, &c) in row.iter().enumerate() {
+            if (x, y) == (pointer_x, pointer_y) {
+                let highlight = frame.passed_zero || frame.landed_on_zero;
+                line.push_str(if highlight { REVERSE } else { BOLD });
+                line.push('@');
+                line.push_str(RESET);
+            } else {
+                line.push(c);
+            }
+        }
+        out.push_str(line.trim_end());
+        out.push('\n');
+    }
+
+    let (Turn::Left(clicks) | Turn::Right(clicks)) = frame.turn;
+    out.push_str(&format!(
+        "turn {} ({}), click {}/{clicks}, passed zero {} times",
+        frame.step, frame.turn, frame.click, frame.crossings,
+    ));
+    if frame.passed_zero {
+        out.push_str(&format!("  {REVERSE}passed zero{RESET}"));
+    }
+    if frame.landed_on_zero {
+        out.push_str(&format!("  {REVERSE}landed on zero{RESET}"));
+    }
+    out.push('\n');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn test_frames_match_the_lock() {
+        let input = include_str!("example.txt");
+        let frames = frames(100, 50, parse(input).unwrap()).collect::<Vec<_>>();
+
+        let landed = frames.iter().filter(|frame| frame.landed_on_zero).count();
+        assert_eq!(landed as u64, crate::part_1(input).unwrap());
+        let passed = frames.iter().filter(|frame| frame.passed_zero).count();
+        assert_eq!(passed as u64, crate::part_2(input).unwrap());
+        assert_eq!(frames.last().unwrap().crossings, passed as u64);
+        assert_eq!(frames.last().unwrap().step, 10);
+    }
+
+    #[test]
+    fn test_frames_click_by_click() {
+        let turns = [Turn::Left(2), Turn::Right(0), Turn::Right(2)];
+        let frames = frames(4, 1, turns).collect::<Vec<_>>();
+        let summary = frames
+            .iter()
+            .map(|frame| (frame.step, frame.click, frame.position, frame.passed_zero))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            [
+                (1, 1, 0, false),
+                (1, 2, 3, true),
+                (2, 0, 3, false),
+                (3, 1, 0, true),
+                (3, 2, 1, false),
+            ]
+        );
+        assert!(!frames[0].landed_on_zero);
+        assert!(frames.iter().all(|frame| !frame.landed_on_zero));
+    }
+
+    #[test]
+    fn test_render() {
+        let frame = Frame {
+            step: 3,
+            turn: Turn::Right(48),
+            click: 48,
+            position: 0,
+            passed_zero: true,
+            landed_on_zero: true,
+            crossings: 2,
+        };
+        let out = render(&frame, 100);
+        let lines = out.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), RADIUS * 2 + 2);
+        assert!(lines[0].contains(&format!("{REVERSE}@{RESET}")));
+        assert!(lines[RADIUS].split_whitespace().any(|word| word == "0"));
+        assert!(
+            lines[RADIUS * 2 + 1].starts_with("turn 3 (R48), click 48/48, passed zero 2 times")
+        );
+        assert!(lines[RADIUS * 2 + 1].contains("landed on zero"));
+
+        let frame = Frame {
+            position: 25,
+            passed_zero: false,
+            landed_on_zero: false,
+            ..frame
+        };
+        let out = render(&frame, 100);
+        let lines = out.lines().collect::<Vec<_>>();
+        assert!(lines[0].contains('0'));
+        assert!(lines[RADIUS].ends_with(&format!("{BOLD}@{RESET}")));
+        assert!(lines[RADIUS].contains(" 25 "));
+        assert!(!out.contains(REVERSE));
+    }
+}
@@ -0,0 +1,72 @@
+use crate::Turn;
+
+/// What happened to the dial during a single turn
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Event {
+    /// The turn that was made
+    pub turn: Turn,
+    /// The position the dial pointed at before the turn
+    pub start: u16,
+    /// The position the dial pointed at after the turn
+    pub end: u16,
+    /// The number of times the dial passed zero during the turn
+    pub crossings: u64,
+}
+
+impl Event {
+    pub const CSV_HEADER: &str = "step,turn,start,end,crossings,landed_on_zero";
+
+    /// Whether the turn left the dial pointing at zero
+    pub fn landed_on_zero(&self) -> bool {
+        self.end == 0
+    }
+
+    /// Describe the event as a CSV row, where `step` is its position in the trace
+    pub fn to_csv(&self, step: usize) -> String {
+        format!(
+            "{step},{},{},{},{},{}",
+            self.turn,
+            self.start,
+            self.end,
+            self.crossings,
+            self.landed_on_zero(),
+        )
+    }
+
+    /// Describe the event for a person to read, where `step` is its position in the trace
+    pub fn to_text(&self, step: usize) -> String {
+        let landed = if self.landed_on_zero() {
+            "  landed on zero"
+        } else {
+            ""
+        };
+        format!(
+            "{step:>5}  {:<6}  {:>3} -> {:<3}  crossings {}{landed}",
+            self.turn.to_string(),
+            self.start,
+            self.end,
+            self.crossings,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format() {
+        let event = Event {
+            turn: Turn::Right(18),
+            start: 82,
+            end: 0,
+            crossings: 1,
+        };
+        assert!(event.landed_on_zero());
+        assert_eq!(event.to_csv(2), "2,R18,82,0,1,true");
+        assert_eq!(
+            event.to_text(2),
+            "    2  R18      82 -> 0    crossings 1  landed on zero"
+        );
+    }
+}
@@ -0,0 +1,68 @@
+use crate::Turn;
+use common::Rng;
+
+/// The shape of a generated list of turns
+#[derive(Clone, Debug)]
+pub struct GeneratorConfig {
+    /// How many turns to write
+    pub turns: usize,
+    /// The most clicks a single turn can have
+    pub max_clicks: u64,
+}
+
+impl GeneratorConfig {
+    /// A config for `size` turns
+    pub fn with_size(size: usize) -> Self {
+        Self {
+            turns: size,
+            max_clicks: 999,
+        }
+    }
+}
+
+/// Write one random turn per line
+///
+/// Only plain turns are written, never repeats, comments or absolute moves, since the
+/// [reference](crate::reference) solution only reads plain turns.
+pub fn generate(seed: u64, config: &GeneratorConfig) -> String {
+    let mut rng = Rng::new(seed);
+    let mut out = String::new();
+    for _ in 0..config.turns {
+        let clicks = rng.range(0, config.max_clicks);
+        let turn = if rng.chance(50) {
+            Turn::Left(clicks)
+        } else {
+            Turn::Right(clicks)
+        };
+        out.push_str(&turn.to_string());
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate() {
+        let config = GeneratorConfig::with_size(50);
+        let turns = crate::parse(&generate(3, &config)).unwrap();
+        assert_eq!(turns.len(), 50);
+        for turn in turns {
+            let (Turn::Left(clicks) | Turn::Right(clicks)) = turn;
+            assert!(clicks <= config.max_clicks);
+        }
+
+        let config = GeneratorConfig {
+            turns: 10,
+            max_clicks: 0,
+        };
+        let turns = crate::parse(&generate(3, &config)).unwrap();
+        assert!(
+            turns
+                .iter()
+                .all(|&turn| matches!(turn, Turn::Left(0) | Turn::Right(0)))
+        );
+    }
+}
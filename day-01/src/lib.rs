@@ -0,0 +1,250 @@
+//! Day 1: turning the dial on a combination lock
+
+use common::{Answer, ParseError, Parsed, Part, Solver};
+use script::Script;
+
+pub use event::Event;
+pub use generate::{GeneratorConfig, generate};
+pub use lock::{ClicksOverflow, Lock, LockBuilder};
+pub use multi_lock::{Carry, MultiLock};
+pub use planner::{PlanError, Planner};
+pub use stats::{Stats, Stretch};
+pub use stream::{Answers, StreamError, solve_reader};
+pub use turn::Turn;
+
+pub mod animate;
+mod event;
+mod generate;
+mod lock;
+mod multi_lock;
+mod planner;
+pub mod reference;
+pub mod script;
+mod stats;
+mod stream;
+mod turn;
+
+/// The puzzle input
+pub const INPUT: &str = include_str!("input.txt");
+
+/// The [`Solver`] for day 1
+pub struct Day01;
+
+impl Solver for Day01 {
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn input(&self) -> &'static str {
+        INPUT
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Box<dyn Parsed + 'a>, ParseError> {
+        Ok(Box::new(script::parse(input, &Lock::new())?))
+    }
+
+    fn part_one(&self, input: &str) -> Result<Answer, ParseError> {
+        part_1(input).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, ParseError> {
+        part_2(input).map(Answer::from)
+    }
+
+    fn generate(&self, seed: u64, size: usize) -> String {
+        generate(seed, &GeneratorConfig::with_size(size))
+    }
+
+    fn reference(&self, part: Part, input: &str) -> Result<Answer, ParseError> {
+        match part {
+            Part::One => reference::part_1(input).map(Answer::from),
+            Part::Two => reference::part_2(input).map(Answer::from),
+        }
+    }
+}
+
+impl Parsed for Script<'_> {
+    fn part_one(&self) -> Result<Answer, ParseError> {
+        answers(self).map(|answers| Answer::from(answers.part_1))
+    }
+
+    fn part_two(&self) -> Result<Answer, ParseError> {
+        answers(self).map(|answers| Answer::from(answers.part_2))
+    }
+}
+
+/// Solve both parts of a parsed script, on a fresh copy of the lock it was parsed for
+fn answers(script: &Script) -> Result<Answers, ParseError> {
+    let mut lock = script.lock();
+    let mut answers = Answers::default();
+    stream::run(script, &mut lock, &mut answers)?;
+    answers.part_2 = lock.clicks();
+    Ok(answers)
+}
+
+/// Parse one turn per line, without any of the extras a [`script`] can have
+pub fn parse(input: &str) -> Result<Vec<Turn>, ParseError> {
+    let mut turns = Vec::new();
+    for line in input.lines() {
+        let turn: Turn = line
+            .parse()
+            .map_err(|err: ParseError| err.within(input, line))?;
+        turns.push(turn);
+    }
+    Ok(turns)
+}
+
+/// Count how many turns leave the dial pointing at zero
+pub fn part_1(input: &str) -> Result<u64, ParseError> {
+    part_1_with_lock(input, Lock::new())
+}
+
+/// Count how many turns of a [`script`] leave the dial of `lock` pointing at zero
+pub fn part_1_with_lock(input: &str, lock: Lock) -> Result<u64, ParseError> {
+    solve(input, lock).map(|answers| answers.part_1)
+}
+
+/// Count how many times the dial passes zero over all of the turns
+pub fn part_2(input: &str) -> Result<u64, ParseError> {
+    part_2_with_lock(input, Lock::new())
+}
+
+/// Count how many times the dial of `lock` passes zero over all of the turns of a [`script`]
+pub fn part_2_with_lock(input: &str, lock: Lock) -> Result<u64, ParseError> {
+    solve(input, lock).map(|answers| answers.part_2)
+}
+
+/// Solve both parts with [`solve_reader`], which can't fail to read from a string
+fn solve(input: &str, lock: Lock) -> Result<Answers, ParseError> {
+    solve_reader(input.as_bytes(), lock).map_err(|err| match err {
+        StreamError::Parse(err) => err,
+        StreamError::Io(err) => unreachable!("reading a string failed: {err}"),
+    })
+}
+
+/// Record what happens to the dial of `lock` on each of the turns of a [`script`]
+pub fn trace(input: &str, mut lock: Lock) -> Result<Vec<Event>, ParseError> {
+    let script = script::parse(input, &lock)?;
+
+    script
+        .turns()
+        .map(|(turn, text)| turn_lock(&mut lock, turn, input, text))
+        .collect()
+}
+
+/// Gather [`Stats`] about the turns of a [`script`]
+pub fn stats(input: &str, lock: Lock) -> Result<Stats, ParseError> {
+    let mut stats = Stats::new(lock.positions());
+    for event in trace(input, lock)? {
+        stats.add(&event);
+    }
+    Ok(stats)
+}
+
+/// Turn every wheel of `lock` with each of the turns of a [`script`]
+pub fn turn_wheels(input: &str, mut lock: MultiLock) -> Result<MultiLock, ParseError> {
+    let script = script::parse(input, &lock.wheels()[0])?;
+
+    for (turn, text) in script.turns() {
+        lock.turn(turn)
+            .map_err(|err| ParseError::new(text, err.to_string()).within(input, text))?;
+    }
+
+    Ok(lock)
+}
+
+/// Turn the lock, blaming `text` of `input` if the clicks can no longer be counted
+fn turn_lock(lock: &mut Lock, turn: Turn, input: &str, text: &str) -> Result<Event, ParseError> {
+    lock.turn(turn)
+        .map_err(|err| ParseError::new(text, err.to_string()).within(input, text))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_1() {
+        let input = include_str!("example.txt");
+        let out = part_1(input).unwrap();
+        assert_eq!(out, 3);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input = include_str!("example.txt");
+        let out = part_2(input).unwrap();
+        assert_eq!(out, 6);
+    }
+
+    #[test]
+    fn test_other_dials() {
+        let input = include_str!("example.txt");
+        for positions in [1, 7, 40, 60, 100, 1000] {
+            for start in [0, positions / 2, positions - 1] {
+                let lock = || Lock::builder().positions(positions).start(start).build();
+                assert_eq!(
+                    part_1_with_lock(input, lock()),
+                    reference::part_1_with_dial(input, positions, start),
+                );
+                assert_eq!(
+                    part_2_with_lock(input, lock()),
+                    reference::part_2_with_dial(input, positions, start),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_trace() {
+        let input = include_str!("example.txt");
+        let events = trace(input, Lock::new()).unwrap();
+        assert_eq!(events.len(), 10);
+        assert_eq!(
+            events[0],
+            Event {
+                turn: Turn::Left(68),
+                start: 50,
+                end: 82,
+                crossings: 1,
+            }
+        );
+        assert!(events.windows(2).all(|pair| pair[0].end == pair[1].start));
+
+        let landed = events.iter().filter(|event| event.landed_on_zero()).count();
+        assert_eq!(landed as u64, part_1(input).unwrap());
+        let crossings = events.iter().map(|event| event.crossings).sum::<u64>();
+        assert_eq!(crossings, part_2(input).unwrap());
+    }
+
+    #[test]
+    fn test_wide_turns() {
+        let input = "L40000\nR18446744073709551615\n";
+        assert_eq!(part_1(input), Ok(0));
+        assert_eq!(part_2(input), Ok(400 + 184467440737095516));
+
+        let lock = || Lock::builder().positions(1).start(0).build();
+        let input = "R18446744073709551615\n";
+        assert_eq!(part_2_with_lock(input, lock()), Ok(u64::MAX));
+
+        let err = part_2_with_lock("R18446744073709551615\nL1\n", lock()).unwrap_err();
+        assert_eq!((err.line(), err.column()), (2, 1));
+        assert_eq!(err.reason(), "too many clicks to count");
+    }
+
+    #[test]
+    fn test_script() {
+        let script = "# comment\nL68 L30\n2x(R48 L5)\n@0 # home\n";
+        let unrolled = "L68\nL30\nR48\nL5\nR48\nL5\nL38\n";
+        assert_eq!(part_1(script), part_1(unrolled));
+        assert_eq!(part_2(script), part_2(unrolled));
+        assert_eq!(trace(script, Lock::new()), trace(unrolled, Lock::new()));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = part_1("L68\nL30\nR4x\n").unwrap_err();
+        assert_eq!((err.line(), err.column()), (3, 2));
+        assert_eq!(err.text(), "4x");
+    }
+}
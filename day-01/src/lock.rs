@@ -0,0 +1,291 @@
+use crate::{Event, Turn};
+use std::{error::Error, fmt};
+
+/// A combination lock with a numbered dial
+///
+/// [`Lock::new`] gives the puzzle's lock: a 100 position dial starting at 50. Use
+/// [`Lock::builder`] for any other dial.
+#[derive(Clone, Debug)]
+pub struct Lock {
+    positions: u16,
+    current: u16,
+    clicks: u64,
+}
+
+impl Lock {
+    /// Create a lock in its starting position
+    pub fn new() -> Self {
+        Self::builder().build()
+    }
+
+    /// Start building a lock, with the puzzle's dial as the default
+    pub fn builder() -> LockBuilder {
+        LockBuilder {
+            positions: 100,
+            start: 50,
+        }
+    }
+
+    /// The number of positions on the dial
+    pub fn positions(&self) -> u16 {
+        self.positions
+    }
+
+    /// The position the dial is currently pointing at
+    pub fn current(&self) -> u16 {
+        self.current
+    }
+
+    /// The number of times the dial has passed zero
+    pub fn clicks(&self) -> u64 {
+        self.clicks
+    }
+
+    /// Turn the dial, counting any time it passes zero
+    ///
+    /// Turning left counts each time the dial moves from 0 to the highest position, so a turn that
+    /// starts on 0 counts but one that stops on 0 doesn't. Turning right is the other way around.
+    ///
+    /// If the total number of clicks would no longer fit in a `u64`, the lock is left as it was.
+    pub fn turn(&mut self, turn: Turn) -> Result<Event, ClicksOverflow> {
+        // Wide enough that no single turn can overflow
+        let positions = self.positions as i128;
+        let val = match turn {
+            Turn::Left(left) => self.current as i128 - left as i128,
+            Turn::Right(right) => self.current as i128 + right as i128,
+        };
+
+        // Each whole revolution below 0 or at or above `positions` passes zero once
+        let crossings =
+            u64::try_from(val.div_euclid(positions).unsigned_abs()).map_err(|_| ClicksOverflow)?;
+        let clicks = self.clicks.checked_add(crossings).ok_or(ClicksOverflow)?;
+
+        let start = self.current;
+        self.current = val.rem_euclid(positions) as u16;
+        self.clicks = clicks;
+        Ok(Event {
+            turn,
+            start,
+            end: self.current,
+            crossings,
+        })
+    }
+}
+
+impl Default for Lock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The number of times a [`Lock`] has passed zero is too big for a `u64`
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct ClicksOverflow;
+
+impl fmt::Display for ClicksOverflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "too many clicks to count")
+    }
+}
+
+impl Error for ClicksOverflow {}
+
+/// Configures a [`Lock`] with a different dial
+#[derive(Copy, Clone, Debug)]
+pub struct LockBuilder {
+    positions: u16,
+    start: u16,
+}
+
+impl LockBuilder {
+    /// The number of positions on the dial, numbered from 0
+    pub fn positions(mut self, positions: u16) -> Self {
+        self.positions = positions;
+        self
+    }
+
+    /// The position the dial points at before the first turn
+    pub fn start(mut self, start: u16) -> Self {
+        self.start = start;
+        self
+    }
+
+    /// Create the lock
+    ///
+    /// Panics if the dial has no positions, or if the start is not one of them.
+    pub fn build(self) -> Lock {
+        assert!(self.positions > 0);
+        assert!(self.start < self.positions);
+
+        Lock {
+            positions: self.positions,
+            current: self.start,
+            clicks: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Rng;
+
+    #[test]
+    fn test_turn() {
+        let mut lock = Lock::new();
+        assert_eq!(lock.current(), 50);
+
+        let event = lock.turn(Turn::Left(68)).unwrap();
+        assert_eq!((event.start, event.end, event.crossings), (50, 82, 1));
+        assert_eq!(lock.current(), 82);
+        assert_eq!(lock.clicks(), 1);
+
+        lock.turn(Turn::Right(18)).unwrap();
+        assert_eq!(lock.current(), 0);
+        assert_eq!(lock.clicks(), 2);
+
+        lock.turn(Turn::Right(250)).unwrap();
+        assert_eq!(lock.current(), 50);
+        assert_eq!(lock.clicks(), 4);
+    }
+
+    #[test]
+    fn test_position_stays_on_the_dial() {
+        let mut rng = Rng::new(1);
+        for _ in 0..100 {
+            let mut lock = Lock::new();
+            let mut position = 50;
+            for _ in 0..100 {
+                let clicks = rng.range(0, 999);
+                let turn = if rng.chance(50) {
+                    position -= clicks as i64;
+                    Turn::Left(clicks)
+                } else {
+                    position += clicks as i64;
+                    Turn::Right(clicks)
+                };
+                lock.turn(turn).unwrap();
+                assert!(lock.current() < 100, "{turn}");
+                assert_eq!(lock.current() as i64, position.rem_euclid(100), "{turn}");
+            }
+        }
+    }
+
+    /// Turn the dial one revolution at a time, which is obviously correct but slow
+    fn turn_by_looping(lock: &mut Lock, turn: Turn) {
+        let positions = lock.positions as i64;
+        let mut val = lock.current as i64;
+        let mut clicks = 0;
+
+        match turn {
+            Turn::Left(left) => {
+                val -= left as i64;
+                while val < 0 {
+                    val += positions;
+                    clicks += 1;
+                }
+            }
+            Turn::Right(right) => {
+                val += right as i64;
+                while val >= positions {
+                    val -= positions;
+                    clicks += 1;
+                }
+            }
+        }
+
+        lock.current = val as u16;
+        lock.clicks += clicks;
+    }
+
+    #[test]
+    fn test_turn_matches_looping() {
+        let dials = (1..=12).map(|positions: u16| (positions, 0..=positions as u64 * 4));
+        let dials = dials.chain([(100, 0..=1000)]);
+        for (positions, clicks) in dials {
+            for start in 0..positions {
+                for clicks in clicks.clone() {
+                    for turn in [Turn::Left(clicks), Turn::Right(clicks)] {
+                        let lock = || Lock::builder().positions(positions).start(start).build();
+                        let mut expected = lock();
+                        turn_by_looping(&mut expected, turn);
+                        let mut actual = lock();
+                        actual.turn(turn).unwrap();
+
+                        let context = format!("{positions} positions from {start}, {turn}");
+                        assert_eq!(actual.current(), expected.current(), "{context}");
+                        assert_eq!(actual.clicks(), expected.clicks(), "{context}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_turn_edge_cases() {
+        // Landing exactly on 0 from the right counts, from the left it doesn't
+        let mut lock = Lock::builder().start(10).build();
+        lock.turn(Turn::Left(10)).unwrap();
+        assert_eq!((lock.current(), lock.clicks()), (0, 0));
+        let mut lock = Lock::builder().start(90).build();
+        lock.turn(Turn::Right(10)).unwrap();
+        assert_eq!((lock.current(), lock.clicks()), (0, 1));
+
+        // Starting on 0 and turning left counts straight away
+        let mut lock = Lock::builder().start(0).build();
+        lock.turn(Turn::Left(1)).unwrap();
+        assert_eq!((lock.current(), lock.clicks()), (99, 1));
+
+        // Large turns are counted one per revolution
+        let mut lock = Lock::new();
+        lock.turn(Turn::Left(65535)).unwrap();
+        assert_eq!((lock.current(), lock.clicks()), (15, 655));
+        let mut lock = Lock::new();
+        lock.turn(Turn::Right(65535)).unwrap();
+        assert_eq!((lock.current(), lock.clicks()), (85, 655));
+        let mut lock = Lock::new();
+        lock.turn(Turn::Left(u64::MAX)).unwrap();
+        assert_eq!((lock.current(), lock.clicks()), (35, 184467440737095516));
+        let mut lock = Lock::new();
+        lock.turn(Turn::Right(u64::MAX)).unwrap();
+        assert_eq!((lock.current(), lock.clicks()), (65, 184467440737095516));
+    }
+
+    #[test]
+    fn test_clicks_overflow() {
+        let mut lock = Lock::builder().positions(1).start(0).build();
+        lock.turn(Turn::Right(u64::MAX)).unwrap();
+        assert_eq!(lock.clicks(), u64::MAX);
+        lock.turn(Turn::Right(0)).unwrap();
+
+        assert_eq!(lock.turn(Turn::Left(1)), Err(ClicksOverflow));
+        assert_eq!((lock.current(), lock.clicks()), (0, u64::MAX));
+    }
+
+    #[test]
+    fn test_builder() {
+        let lock = Lock::builder().build();
+        assert_eq!((lock.positions(), lock.current()), (100, 50));
+
+        let mut lock = Lock::builder().positions(40).start(10).build();
+        assert_eq!((lock.positions(), lock.current()), (40, 10));
+
+        lock.turn(Turn::Left(11)).unwrap();
+        assert_eq!(lock.current(), 39);
+        assert_eq!(lock.clicks(), 1);
+
+        lock.turn(Turn::Right(81)).unwrap();
+        assert_eq!(lock.current(), 0);
+        assert_eq!(lock.clicks(), 4);
+
+        let mut lock = Lock::builder().positions(1).start(0).build();
+        lock.turn(Turn::Right(5)).unwrap();
+        assert_eq!((lock.current(), lock.clicks()), (0, 5));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_builder_start_off_the_dial() {
+        Lock::builder().positions(40).start(40).build();
+    }
+}
@@ -1,133 +1,315 @@
-use std::str::FromStr;
-
-fn main() {
-    let input = include_str!("input.txt");
-    let part_1 = part_1(input);
-    println!("{}", part_1);
-    let part_2 = part_2(input);
-    println!("{}", part_2);
-}
-
-fn part_1(input: &str) -> u16 {
-    let mut turns = Vec::new();
-    for line in input.lines() {
-        let turn: Turn = line.parse().expect("Expected parse");
-        turns.push(turn);
-    }
+use common::{InputError, InputSource};
+use day_01::{Carry, Event, Lock, MultiLock, Planner, StreamError, animate};
+use std::{
+    borrow::Cow,
+    error::Error,
+    io::{self, Write},
+    process::ExitCode,
+    thread,
+    time::Duration,
+};
 
-    let mut lock = Lock::new();
-    let mut count = 0;
+const USAGE: &str = "\
+usage: day-01 [INPUT|-]
+       day-01 trace [--csv] [INPUT|-]
+       day-01 plan [--alternate] [--max-clicks N] [--positions N] [--start N] TARGET...
+       day-01 wheels [--wheels N] [--carry none|same|opposite] [--carry-clicks N]
+                     [--positions N] [--start N] [INPUT|-]
+       day-01 animate [--delay MS] [--pause-at TURN] [--positions N] [--start N] [INPUT|-]
+       day-01 stats [--top N] [--positions N] [--start N] [INPUT|-]";
 
-    for turn in turns {
-        lock.turn(turn);
-        if lock.current() == 0 {
-            count += 1;
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
         }
     }
-
-    count
 }
 
-fn part_2(input: &str) -> u16 {
-    let mut turns = Vec::new();
-    for line in input.lines() {
-        let turn: Turn = line.parse().expect("Expected parse");
-        turns.push(turn);
+fn run() -> Result<(), Box<dyn Error>> {
+    let mut args = std::env::args().skip(1).peekable();
+    match args.peek().map(String::as_str) {
+        Some("trace") => {
+            args.next();
+            trace(args)
+        }
+        Some("plan") => {
+            args.next();
+            plan(args)
+        }
+        Some("wheels") => {
+            args.next();
+            wheels(args)
+        }
+        Some("animate") => {
+            args.next();
+            animate(args)
+        }
+        Some("stats") => {
+            args.next();
+            stats(args)
+        }
+        _ => solve(args),
     }
+}
 
-    let mut lock = Lock::new();
-
-    for turn in turns {
-        lock.turn(turn);
-    }
+/// Print the answers to both parts, reading the turns a line at a time
+fn solve(args: impl Iterator<Item = String>) -> Result<(), Box<dyn Error>> {
+    let source = InputSource::from_arg(input_arg(args, |_| false)?.as_deref());
+    let reader = source.open(day_01::INPUT)?;
+    let answers = match day_01::solve_reader(reader, Lock::new()) {
+        Ok(answers) => answers,
+        Err(StreamError::Io(err)) => return Err(source.error(err).into()),
+        Err(StreamError::Parse(err)) => return Err(err.into()),
+    };
+    println!("{}", answers.part_1);
+    println!("{}", answers.part_2);
 
-    lock.clicks()
+    Ok(())
 }
 
-struct Lock {
-    current: u16,
-    clicks: u16,
-}
+/// Print what happens to the dial on every turn
+fn trace(args: impl Iterator<Item = String>) -> Result<(), Box<dyn Error>> {
+    let mut csv = false;
+    let input = input_arg(args, |flag| match flag {
+        "--csv" => {
+            csv = true;
+            true
+        }
+        _ => false,
+    })?;
+    let input = load(input)?;
 
-impl Lock {
-    fn new() -> Self {
-        Self {
-            current: 50,
-            clicks: 0,
+    let events = day_01::trace(&input, Lock::new())?;
+    if csv {
+        println!("{}", Event::CSV_HEADER);
+    }
+    for (step, event) in events.iter().enumerate() {
+        if csv {
+            println!("{}", event.to_csv(step + 1));
+        } else {
+            println!("{}", event.to_text(step + 1));
         }
     }
 
-    fn current(&self) -> u16 {
-        self.current
+    Ok(())
+}
+
+/// Print the shortest list of turns that stops the dial at each target, one per line
+fn plan(mut args: impl Iterator<Item = String>) -> Result<(), Box<dyn Error>> {
+    let mut planner = Planner::new();
+    let mut positions = 100;
+    let mut start = 50;
+    let mut targets = Vec::new();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--alternate" => planner = planner.alternate(true),
+            "--max-clicks" => planner = planner.max_clicks(number(&arg, args.next())?),
+            "--positions" => positions = number(&arg, args.next())?,
+            "--start" => start = number(&arg, args.next())?,
+            _ if !arg.starts_with("--") => targets.push(number("target", Some(arg))?),
+            _ => return Err(format!("unexpected argument {arg:?}\n{USAGE}").into()),
+        }
+    }
+    if positions == 0 || start >= positions {
+        return Err(format!("{start} is not on a dial with {positions} positions").into());
     }
+    let lock = Lock::builder().positions(positions).start(start).build();
 
-    fn clicks(&self) -> u16 {
-        self.clicks
+    for turn in planner.plan(&lock, &targets)? {
+        println!("{turn}");
     }
 
-    fn turn(&mut self, turn: Turn) {
-        let mut val = self.current as i16;
-        let mut clicks = 0;
+    Ok(())
+}
 
-        match turn {
-            Turn::Left(left) => {
-                val = val - left as i16;
-                while val < 0 {
-                    val += 100;
-                    clicks += 1;
-                }
-            }
-            Turn::Right(right) => {
-                val = val + right as i16;
-                while val >= 100 {
-                    val -= 100;
-                    clicks += 1;
-                }
-            }
+/// Turn a lock with several wheels and report on each of them
+fn wheels(mut args: impl Iterator<Item = String>) -> Result<(), Box<dyn Error>> {
+    let mut wheels = 2;
+    let mut carry = "same".to_string();
+    let mut carry_clicks = 1;
+    let mut positions = 100;
+    let mut start = 50;
+    let mut input = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--wheels" => wheels = number(&arg, args.next())?,
+            "--carry" => carry = args.next().ok_or("--carry needs a value")?,
+            "--carry-clicks" => carry_clicks = number(&arg, args.next())?,
+            "--positions" => positions = number(&arg, args.next())?,
+            "--start" => start = number(&arg, args.next())?,
+            _ if input.is_none() && !arg.starts_with("--") => input = Some(arg),
+            _ => return Err(format!("unexpected argument {arg:?}\n{USAGE}").into()),
         }
+    }
+    let carry = match carry.as_str() {
+        "none" => Carry::None,
+        "same" => Carry::Same(carry_clicks),
+        "opposite" => Carry::Opposite(carry_clicks),
+        _ => return Err(format!("invalid carry {carry:?}").into()),
+    };
+    if wheels == 0 {
+        return Err("a lock needs at least one wheel".into());
+    }
+    if positions == 0 || start >= positions {
+        return Err(format!("{start} is not on a dial with {positions} positions").into());
+    }
+    let wheels = (0..wheels)
+        .map(|_| Lock::builder().positions(positions).start(start).build())
+        .collect();
 
-        self.current = val as u16;
-        self.clicks += clicks;
+    let input = load(input)?;
+    let lock = day_01::turn_wheels(&input, MultiLock::new(wheels, carry))?;
+    for (i, (wheel, zero_hits)) in lock.wheels().iter().zip(lock.zero_hits()).enumerate() {
+        println!(
+            "wheel {}: at {}, landed on zero {zero_hits} times, passed zero {} times",
+            i + 1,
+            wheel.current(),
+            wheel.clicks(),
+        );
     }
-}
+    println!("total: passed zero {} times", lock.clicks());
 
-#[derive(Copy, Clone)]
-enum Turn {
-    Left(u16),
-    Right(u16),
+    Ok(())
 }
 
-impl FromStr for Turn {
-    type Err = ();
+/// Draw the dial turning one click at a time
+fn animate(mut args: impl Iterator<Item = String>) -> Result<(), Box<dyn Error>> {
+    let mut delay = 20;
+    let mut pause_at = None;
+    let mut positions = 100;
+    let mut start = 50;
+    let mut input = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--delay" => delay = number(&arg, args.next())?,
+            "--pause-at" => pause_at = Some(number::<usize>(&arg, args.next())?),
+            "--positions" => positions = number(&arg, args.next())?,
+            "--start" => start = number(&arg, args.next())?,
+            _ if input.is_none() && !arg.starts_with("--") => input = Some(arg),
+            _ => return Err(format!("unexpected argument {arg:?}\n{USAGE}").into()),
+        }
+    }
+    if positions == 0 || start >= positions {
+        return Err(format!("{start} is not on a dial with {positions} positions").into());
+    }
+    if pause_at.is_some() && input.as_deref() == Some("-") {
+        return Err(
+            "--pause-at waits for Enter on stdin, so the turns can't come from there".into(),
+        );
+    }
+
+    let input = load(input)?;
+    let lock = Lock::builder().positions(positions).start(start).build();
+    let script = day_01::script::parse(&input, &lock)?;
+    let frames = animate::frames(positions, start, script.turns().map(|(turn, _)| turn));
+    for frame in frames {
+        print!("\x1b[H\x1b[2J{}", animate::render(&frame, positions));
+        io::stdout().flush()?;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if let Some(left) = s.strip_prefix("L") {
-            let left = left.parse().map_err(|_| ())?;
-            Ok(Turn::Left(left))
-        } else if let Some(right) = s.strip_prefix("R") {
-            let right = right.parse().map_err(|_| ())?;
-            Ok(Turn::Right(right))
+        if pause_at == Some(frame.step) && frame.click <= 1 {
+            print!("paused at turn {}, press Enter to carry on", frame.step);
+            io::stdout().flush()?;
+            io::stdin().read_line(&mut String::new())?;
         } else {
-            Err(())
+            thread::sleep(Duration::from_millis(delay));
         }
     }
+
+    Ok(())
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Print a report about where the turns take the dial
+fn stats(mut args: impl Iterator<Item = String>) -> Result<(), Box<dyn Error>> {
+    let mut top = 5;
+    let mut positions = 100;
+    let mut start = 50;
+    let mut input = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--top" => top = number(&arg, args.next())?,
+            "--positions" => positions = number(&arg, args.next())?,
+            "--start" => start = number(&arg, args.next())?,
+            _ if input.is_none() && !arg.starts_with("--") => input = Some(arg),
+            _ => return Err(format!("unexpected argument {arg:?}\n{USAGE}").into()),
+        }
+    }
+    if positions == 0 || start >= positions {
+        return Err(format!("{start} is not on a dial with {positions} positions").into());
+    }
+
+    let input = load(input)?;
+    let lock = Lock::builder().positions(positions).start(start).build();
+    let stats = day_01::stats(&input, lock)?;
+
+    println!("turns: {}", stats.turns());
+    println!("clicks turned left: {}", stats.left());
+    println!("clicks turned right: {}", stats.right());
+    let longest = stats.longest_without_zero();
+    if longest.len > 0 {
+        println!(
+            "longest stretch without landing on zero: {} turns, from turn {} to {}",
+            longest.len,
+            longest.start,
+            longest.start + longest.len - 1,
+        );
+    }
+
+    println!();
+    println!("most landed on:");
+    for (position, landings) in stats.most_visited(top) {
+        println!("  {position:>5}  {landings} times");
+    }
 
-    #[test]
-    fn test_part_1() {
-        let input = include_str!("example.txt");
-        let out = part_1(input);
-        assert_eq!(out, 3);
+    println!();
+    println!("landings by position:");
+    let most = stats.landings().iter().copied().max().unwrap_or(0).max(1);
+    for (position, &landings) in stats.landings().iter().enumerate() {
+        let bar = "#".repeat((landings * 50).div_ceil(most) as usize);
+        println!("  {position:>5} | {bar:<50} {landings}");
     }
 
-    #[test]
-    fn test_part_2() {
-        let input = include_str!("example.txt");
-        let out = part_2(input);
-        assert_eq!(out, 6);
+    println!();
+    let crossings = stats
+        .crossings()
+        .iter()
+        .map(|&(step, times)| match times {
+            1 => step.to_string(),
+            _ => format!("{step} ({times} times)"),
+        })
+        .collect::<Vec<_>>();
+    println!("turns that passed zero: {}", crossings.join(", "));
+
+    Ok(())
+}
+
+fn number<T: std::str::FromStr>(name: &str, value: Option<String>) -> Result<T, String> {
+    let value = value.ok_or_else(|| format!("{name} needs a value"))?;
+    value
+        .parse()
+        .map_err(|_| format!("invalid {} {value:?}", name.trim_start_matches("--")))
+}
+
+/// Find the optional input argument, handing every other argument to `flag` to accept or reject
+fn input_arg(
+    args: impl Iterator<Item = String>,
+    mut flag: impl FnMut(&str) -> bool,
+) -> Result<Option<String>, String> {
+    let mut input = None;
+    for arg in args {
+        if flag(&arg) {
+            continue;
+        }
+        if input.is_some() || arg.starts_with("--") {
+            return Err(format!("unexpected argument {arg:?}\n{USAGE}"));
+        }
+        input = Some(arg);
     }
+    Ok(input)
+}
+
+fn load(arg: Option<String>) -> Result<Cow<'static, str>, InputError> {
+    InputSource::from_arg(arg.as_deref()).load(day_01::INPUT)
 }
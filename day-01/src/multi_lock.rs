@@ -0,0 +1,166 @@
+use crate::{ClicksOverflow, Lock, Turn};
+
+/// How turning one wheel of a [`MultiLock`] moves the wheel inside it
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Carry {
+    /// The inner wheels never move
+    None,
+    /// Each time a wheel passes zero, the next wheel turns this many clicks the same way
+    Same(u64),
+    /// Each time a wheel passes zero, the next wheel turns this many clicks the other way
+    Opposite(u64),
+}
+
+impl Carry {
+    /// The turn the next wheel makes when a wheel passes zero `crossings` times during `turn`
+    fn carry(self, turn: Turn, crossings: u64) -> Result<Option<Turn>, ClicksOverflow> {
+        let (clicks, same) = match self {
+            Carry::None => return Ok(None),
+            Carry::Same(clicks) => (clicks, true),
+            Carry::Opposite(clicks) => (clicks, false),
+        };
+        let clicks = crossings.checked_mul(clicks).ok_or(ClicksOverflow)?;
+        if clicks == 0 {
+            return Ok(None);
+        }
+        Ok(Some(match (turn, same) {
+            (Turn::Left(_), true) | (Turn::Right(_), false) => Turn::Left(clicks),
+            (Turn::Right(_), true) | (Turn::Left(_), false) => Turn::Right(clicks),
+        }))
+    }
+}
+
+/// A combination lock with several wheels behind one dial
+///
+/// Turns move the outermost wheel, and each wheel carries the one inside it along as it passes
+/// zero. Every wheel follows the same rules as a [`Lock`].
+#[derive(Clone, Debug)]
+pub struct MultiLock {
+    wheels: Vec<Lock>,
+    carry: Carry,
+    zero_hits: Vec<u64>,
+    clicks: u64,
+}
+
+impl MultiLock {
+    /// Create a lock from its wheels, outermost first
+    ///
+    /// Panics if there are no wheels.
+    pub fn new(wheels: Vec<Lock>, carry: Carry) -> Self {
+        assert!(!wheels.is_empty());
+
+        Self {
+            zero_hits: vec![0; wheels.len()],
+            wheels,
+            carry,
+            clicks: 0,
+        }
+    }
+
+    /// The wheels, outermost first
+    pub fn wheels(&self) -> &[Lock] {
+        &self.wheels
+    }
+
+    /// How many times each wheel has been turned and left pointing at zero, outermost first
+    pub fn zero_hits(&self) -> &[u64] {
+        &self.zero_hits
+    }
+
+    /// The number of times any of the wheels has passed zero
+    pub fn clicks(&self) -> u64 {
+        self.clicks
+    }
+
+    /// Turn the outermost wheel, carrying the inner wheels along
+    ///
+    /// If any count would no longer fit in a `u64`, the lock is left as it was.
+    pub fn turn(&mut self, turn: Turn) -> Result<(), ClicksOverflow> {
+        let mut next = self.clone();
+        let mut turn = Some(turn);
+        for (wheel, zero_hits) in next.wheels.iter_mut().zip(&mut next.zero_hits) {
+            let Some(this) = turn else {
+                break;
+            };
+            let event = wheel.turn(this)?;
+            if event.landed_on_zero() {
+                *zero_hits += 1;
+            }
+            next.clicks = next
+                .clicks
+                .checked_add(event.crossings)
+                .ok_or(ClicksOverflow)?;
+            turn = next.carry.carry(this, event.crossings)?;
+        }
+
+        *self = next;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wheels(count: usize, positions: u16) -> Vec<Lock> {
+        (0..count)
+            .map(|_| Lock::builder().positions(positions).start(0).build())
+            .collect()
+    }
+
+    fn positions(lock: &MultiLock) -> Vec<u16> {
+        lock.wheels().iter().map(Lock::current).collect()
+    }
+
+    #[test]
+    fn test_odometer() {
+        let mut lock = MultiLock::new(wheels(3, 10), Carry::Same(1));
+        lock.turn(Turn::Right(7)).unwrap();
+        assert_eq!(positions(&lock), [7, 0, 0]);
+
+        lock.turn(Turn::Right(456)).unwrap();
+        assert_eq!(positions(&lock), [3, 6, 4]);
+        assert_eq!(lock.clicks(), 46 + 4);
+
+        lock.turn(Turn::Left(463)).unwrap();
+        assert_eq!(positions(&lock), [0, 0, 0]);
+        assert_eq!(lock.zero_hits(), [1, 1, 1]);
+    }
+
+    #[test]
+    fn test_carry() {
+        let mut lock = MultiLock::new(wheels(2, 10), Carry::None);
+        lock.turn(Turn::Right(25)).unwrap();
+        assert_eq!(positions(&lock), [5, 0]);
+        assert_eq!(lock.zero_hits(), [0, 0]);
+
+        let mut lock = MultiLock::new(wheels(2, 10), Carry::Opposite(3));
+        lock.turn(Turn::Right(25)).unwrap();
+        assert_eq!(positions(&lock), [5, 4]);
+        assert_eq!(lock.clicks(), 2 + 1);
+    }
+
+    #[test]
+    fn test_outer_wheel_is_a_lock() {
+        let input = include_str!("example.txt");
+        let lock = MultiLock::new(vec![Lock::new(), Lock::new()], Carry::Same(1));
+        let lock = crate::turn_wheels(input, lock).unwrap();
+        assert_eq!(lock.zero_hits()[0], crate::part_1(input).unwrap());
+        assert_eq!(lock.wheels()[0].clicks(), crate::part_2(input).unwrap());
+    }
+
+    #[test]
+    fn test_overflow() {
+        let mut lock = MultiLock::new(wheels(2, 1), Carry::Same(1));
+        lock.turn(Turn::Right(u64::MAX / 2)).unwrap();
+        assert_eq!(lock.clicks(), u64::MAX - 1);
+
+        // The outer wheel's count fits but the total doesn't, so nothing changes
+        assert_eq!(lock.turn(Turn::Right(1)), Err(ClicksOverflow));
+        assert_eq!(lock.clicks(), u64::MAX - 1);
+        assert_eq!(lock.wheels()[0].clicks(), u64::MAX / 2);
+
+        let mut lock = MultiLock::new(wheels(2, 1), Carry::Same(3));
+        assert_eq!(lock.turn(Turn::Right(u64::MAX / 2)), Err(ClicksOverflow));
+    }
+}
@@ -0,0 +1,457 @@
+use crate::{Lock, Turn};
+use std::{collections::BTreeMap, error::Error, fmt};
+
+/// Works out the turns that make a lock's dial stop at each of a list of positions
+///
+/// A plan has as few turns as possible, and of those, as few clicks as possible. A turn is never
+/// zero clicks.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Planner {
+    alternate: bool,
+    max_clicks: Option<u64>,
+}
+
+impl Planner {
+    /// A planner that can turn either way, as far as it likes
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether each turn must go the opposite way to the one before
+    pub fn alternate(mut self, alternate: bool) -> Self {
+        self.alternate = alternate;
+        self
+    }
+
+    /// The most clicks a single turn can have
+    pub fn max_clicks(mut self, max_clicks: u64) -> Self {
+        self.max_clicks = Some(max_clicks);
+        self
+    }
+
+    /// Plan the turns that take the dial of `lock` to each of `targets` in order
+    pub fn plan(&self, lock: &Lock, targets: &[u16]) -> Result<Vec<Turn>, PlanError> {
+        let positions = lock.positions();
+        if let Some(&target) = targets.iter().find(|&&target| target >= positions) {
+            return Err(PlanError::OffTheDial(target));
+        }
+
+        // The cheapest plan so far for each direction the dial could have last been turned
+        let mut routes = BTreeMap::from([(None, Route::default())]);
+        let mut from = lock.current();
+        for &target in targets {
+            let mut next = BTreeMap::new();
+            for (&last, route) in &routes {
+                for leg in self.legs(positions, from, target, last) {
+                    let last = leg.last().map(|&turn| Direction::of(turn)).or(last);
+                    let route = route.extend(&leg);
+                    let best = next.entry(last).or_insert_with(|| route.clone());
+                    if route.cost() < best.cost() {
+                        *best = route;
+                    }
+                }
+            }
+            if next.is_empty() {
+                return Err(PlanError::Unreachable(target));
+            }
+            routes = next;
+            from = target;
+        }
+
+        let route = routes
+            .into_values()
+            .min_by_key(Route::cost)
+            .expect("there is always at least one route");
+        if !self.verify(lock.clone(), targets, &route.turns) {
+            return Err(PlanError::FailedCheck);
+        }
+        Ok(route.turns)
+    }
+
+    /// Check by simulation that `turns` follow the rules and stop the dial of `lock` at each of
+    /// `targets` in order
+    pub fn verify(&self, mut lock: Lock, targets: &[u16], turns: &[Turn]) -> bool {
+        let mut targets = targets.iter().peekable();
+        while targets.next_if_eq(&&lock.current()).is_some() {}
+
+        let mut last = None;
+        for &turn in turns {
+            let direction = Direction::of(turn);
+            let (Turn::Left(clicks) | Turn::Right(clicks)) = turn;
+            if clicks == 0 || clicks > self.max_clicks.unwrap_or(u64::MAX) {
+                return false;
+            }
+            if self.alternate && last == Some(direction) {
+                return false;
+            }
+            last = Some(direction);
+
+            if lock.turn(turn).is_err() {
+                return false;
+            }
+            while targets.next_if_eq(&&lock.current()).is_some() {}
+        }
+
+        targets.peek().is_none()
+    }
+
+    /// The cheapest ways to get from `from` to `to`, one for each direction the last turn could go
+    fn legs(&self, positions: u16, from: u16, to: u16, last: Option<Direction>) -> Vec<Vec<Turn>> {
+        if from == to {
+            return vec![Vec::new()];
+        }
+
+        // Turning a full revolution or more never gets anywhere a shorter turn doesn't
+        let max = self.max_clicks.unwrap_or(u64::MAX).min(positions as u64);
+        if max == 0 {
+            return Vec::new();
+        }
+
+        let firsts = match last {
+            Some(last) if self.alternate => vec![last.opposite()],
+            _ => vec![Direction::Left, Direction::Right],
+        };
+        firsts
+            .into_iter()
+            .flat_map(|first| {
+                let distance = first.distance(positions, from, to);
+                if self.alternate {
+                    alternating(positions as u64, max, distance)
+                        .into_iter()
+                        .map(|(forward, backward)| first.alternate(&forward, &backward))
+                        .collect()
+                } else {
+                    vec![first.repeat(distance, max)]
+                }
+            })
+            .collect()
+    }
+}
+
+/// The clicks for the cheapest ways to move `distance` positions forward around a dial of
+/// `positions` positions, alternating forward and backward turns of at most `max` clicks
+///
+/// There is one way for each parity of the number of turns, since that decides which way the last
+/// turn goes. Each way is the clicks of the forward turns and the backward turns.
+fn alternating(positions: u64, max: u64, distance: u64) -> Vec<(Vec<u64>, Vec<u64>)> {
+    let (positions, max, distance) = (positions as i128, max as i128, distance as i128);
+
+    // With `t` turns the reachable distances form a range `t * (max - 1)` wide, so once that covers
+    // the whole dial there's no point trying more
+    let turns = if max == 1 {
+        2
+    } else {
+        (positions - 1 + max - 2) / (max - 1) + 1
+    };
+
+    let mut found = [false; 2];
+    let mut ways = Vec::new();
+    for t in 1..=turns.max(2) {
+        if found[(t % 2) as usize] {
+            continue;
+        }
+
+        let forward = (t + 1) / 2;
+        let backward = t / 2;
+        let (low, high) = (forward - backward * max, forward * max - backward);
+
+        // The total clicks for a net distance is smallest when the net is `forward - backward`
+        let best = forward - backward;
+        let below = high.min(best);
+        let below = below - (below - distance).rem_euclid(positions);
+        let above = low.max(best);
+        let above = above + (distance - above).rem_euclid(positions);
+        let cost = |net: i128| (net + 2 * backward).max(2 * forward - net);
+        let net = [below, above]
+            .into_iter()
+            .filter(|net| (low..=high).contains(net))
+            .min_by_key(|&net| cost(net));
+
+        if let Some(net) = net {
+            found[(t % 2) as usize] = true;
+            let backward_clicks = backward.max(forward - net);
+            let forward_clicks = net + backward_clicks;
+            ways.push((
+                spread(forward, forward_clicks, max),
+                spread(backward, backward_clicks, max),
+            ));
+        }
+    }
+    ways
+}
+
+/// Split `total` clicks into `count` turns of between 1 and `max` clicks each
+fn spread(count: i128, total: i128, max: i128) -> Vec<u64> {
+    let mut extra = total - count;
+    (0..count)
+        .map(|_| {
+            let add = extra.min(max - 1);
+            extra -= add;
+            (1 + add) as u64
+        })
+        .collect()
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+enum Direction {
+    Left,
+    Right,
+}
+
+impl Direction {
+    fn of(turn: Turn) -> Self {
+        match turn {
+            Turn::Left(_) => Direction::Left,
+            Turn::Right(_) => Direction::Right,
+        }
+    }
+
+    fn opposite(self) -> Self {
+        match self {
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+
+    fn turn(self, clicks: u64) -> Turn {
+        match self {
+            Direction::Left => Turn::Left(clicks),
+            Direction::Right => Turn::Right(clicks),
+        }
+    }
+
+    /// How many clicks it takes to get from `from` to `to` turning this way
+    fn distance(self, positions: u16, from: u16, to: u16) -> u64 {
+        let (positions, from, to) = (positions as u64, from as u64, to as u64);
+        match self {
+            Direction::Left => (from + positions - to) % positions,
+            Direction::Right => (to + positions - from) % positions,
+        }
+    }
+
+    /// Turn this way `distance` clicks, in as few turns of at most `max` clicks as possible
+    fn repeat(self, distance: u64, max: u64) -> Vec<Turn> {
+        let mut turns = vec![self.turn(max); (distance / max) as usize];
+        if !distance.is_multiple_of(max) {
+            turns.push(self.turn(distance % max));
+        }
+        turns
+    }
+
+    /// Turn this way and then the opposite way, taking the clicks from each list in turn
+    fn alternate(self, forward: &[u64], backward: &[u64]) -> Vec<Turn> {
+        let mut turns = Vec::new();
+        for (i, &clicks) in forward.iter().enumerate() {
+            turns.push(self.turn(clicks));
+            if let Some(&clicks) = backward.get(i) {
+                turns.push(self.opposite().turn(clicks));
+            }
+        }
+        turns
+    }
+}
+
+/// A plan for the targets so far
+#[derive(Clone, Debug, Default)]
+struct Route {
+    turns: Vec<Turn>,
+    clicks: u128,
+}
+
+impl Route {
+    fn extend(&self, leg: &[Turn]) -> Self {
+        let mut route = self.clone();
+        for &turn in leg {
+            let (Turn::Left(clicks) | Turn::Right(clicks)) = turn;
+            route.turns.push(turn);
+            route.clicks += clicks as u128;
+        }
+        route
+    }
+
+    /// Fewer turns is better, then fewer clicks
+    fn cost(&self) -> (usize, u128) {
+        (self.turns.len(), self.clicks)
+    }
+}
+
+/// Why a [`Planner`] couldn't make a plan
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum PlanError {
+    /// A target is not one of the dial's positions
+    OffTheDial(u16),
+    /// No turns that follow the rules can get to a target
+    Unreachable(u16),
+    /// The planned turns didn't get to every target when they were tried on the lock, such as when
+    /// it can't count any more clicks
+    FailedCheck,
+}
+
+impl fmt::Display for PlanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PlanError::OffTheDial(target) => write!(f, "{target} is not on the dial"),
+            PlanError::Unreachable(target) => {
+                write!(f, "{target} can't be reached with turns like these")
+            }
+            PlanError::FailedCheck => write!(f, "the planned turns don't work on this lock"),
+        }
+    }
+}
+
+impl Error for PlanError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Rng;
+    use std::{cmp::Reverse, collections::BinaryHeap};
+
+    fn plan(planner: Planner, targets: &[u16]) -> Result<Vec<Turn>, PlanError> {
+        planner.plan(&Lock::new(), targets)
+    }
+
+    #[test]
+    fn test_plan() {
+        use Turn::{Left, Right};
+
+        let turns = plan(Planner::new(), &[12, 34, 56]).unwrap();
+        assert_eq!(turns, [Left(38), Right(22), Right(22)]);
+
+        let turns = plan(Planner::new().alternate(true), &[12, 34, 56]).unwrap();
+        assert_eq!(turns, [Left(38), Right(22), Left(78)]);
+
+        let turns = plan(Planner::new().max_clicks(10), &[12]).unwrap();
+        assert_eq!(turns, [Left(10), Left(10), Left(10), Left(8)]);
+
+        let turns = plan(Planner::new().alternate(true).max_clicks(10), &[12]).unwrap();
+        assert_eq!(turns.len(), 9);
+        assert!(
+            Planner::new()
+                .alternate(true)
+                .max_clicks(10)
+                .verify(Lock::new(), &[12], &turns)
+        );
+
+        assert_eq!(plan(Planner::new(), &[50, 50]), Ok(vec![]));
+        assert_eq!(plan(Planner::new(), &[]), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_plan_errors() {
+        assert_eq!(
+            plan(Planner::new(), &[100]),
+            Err(PlanError::OffTheDial(100))
+        );
+        assert_eq!(
+            plan(Planner::new().max_clicks(0), &[12]),
+            Err(PlanError::Unreachable(12))
+        );
+        assert_eq!(
+            plan(Planner::new().alternate(true).max_clicks(1), &[53]),
+            Err(PlanError::Unreachable(53))
+        );
+
+        // One click at a time, back and forth, the dial never gets further than 51
+        assert_eq!(
+            plan(Planner::new().alternate(true).max_clicks(1), &[51, 50, 49]),
+            Err(PlanError::Unreachable(49))
+        );
+
+        // The lock has already counted as many clicks as it can, so passing zero again fails
+        let mut lock = Lock::builder().positions(2).start(0).build();
+        lock.turn(Turn::Right(u64::MAX)).unwrap();
+        lock.turn(Turn::Right(u64::MAX)).unwrap();
+        assert_eq!(lock.clicks(), u64::MAX);
+        assert_eq!(
+            Planner::new().plan(&lock, &[1, 0]),
+            Err(PlanError::FailedCheck)
+        );
+    }
+
+    #[test]
+    fn test_verify() {
+        let planner = Planner::new().alternate(true).max_clicks(40);
+        let lock = || Lock::new();
+        assert!(planner.verify(lock(), &[12], &[Turn::Left(38)]));
+        assert!(!planner.verify(lock(), &[12], &[Turn::Left(19), Turn::Left(19)]));
+        assert!(!planner.verify(lock(), &[12], &[Turn::Right(62)]));
+        assert!(!planner.verify(lock(), &[12, 34], &[Turn::Left(38)]));
+        assert!(!planner.verify(lock(), &[12], &[Turn::Left(0), Turn::Left(38)]));
+    }
+
+    /// The cheapest plan's cost, found by searching every turn from every position
+    fn search(planner: Planner, lock: &Lock, targets: &[u16]) -> Option<(usize, u128)> {
+        let positions = lock.positions() as u64;
+        let max = planner.max_clicks.unwrap();
+        let reached = |mut index: usize, position: u16| {
+            while targets.get(index) == Some(&position) {
+                index += 1;
+            }
+            index
+        };
+
+        let start = (lock.current(), None, reached(0, lock.current()));
+        let mut best = BTreeMap::from([(start, (0, 0))]);
+        let mut queue = BinaryHeap::from([Reverse(((0, 0), start))]);
+        while let Some(Reverse((cost, (position, last, index)))) = queue.pop() {
+            if index == targets.len() {
+                return Some(cost);
+            }
+            if best.get(&(position, last, index)) != Some(&cost) {
+                continue;
+            }
+            for direction in [Direction::Left, Direction::Right] {
+                if planner.alternate && last == Some(direction) {
+                    continue;
+                }
+                for clicks in 1..=max {
+                    let next = match direction {
+                        Direction::Left => (position as u64 + positions * max - clicks) % positions,
+                        Direction::Right => (position as u64 + clicks) % positions,
+                    } as u16;
+                    let state = (next, Some(direction), reached(index, next));
+                    let cost = (cost.0 + 1, cost.1 + clicks as u128);
+                    if best.get(&state).is_none_or(|&best| cost < best) {
+                        best.insert(state, cost);
+                        queue.push(Reverse((cost, state)));
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    #[test]
+    fn test_plan_matches_search() {
+        let mut rng = Rng::new(15);
+        for _ in 0..2000 {
+            let positions = rng.range(1, 9) as u16;
+            let start = rng.range(0, positions as u64 - 1) as u16;
+            let targets = (0..rng.range(0, 4))
+                .map(|_| rng.range(0, positions as u64 - 1) as u16)
+                .collect::<Vec<_>>();
+            let planner = Planner::new()
+                .alternate(rng.chance(50))
+                .max_clicks(rng.range(1, positions as u64 + 2));
+
+            let lock = Lock::builder().positions(positions).start(start).build();
+            let context = format!("{planner:?} {positions} positions from {start} to {targets:?}");
+            let expected = search(planner, &lock, &targets);
+            match planner.plan(&lock, &targets) {
+                Ok(turns) => {
+                    assert!(planner.verify(lock.clone(), &targets, &turns), "{context}");
+                    let clicks = turns
+                        .iter()
+                        .map(|&(Turn::Left(clicks) | Turn::Right(clicks))| clicks as u128)
+                        .sum();
+                    assert_eq!(Some((turns.len(), clicks)), expected, "{context}");
+                }
+                Err(err) => {
+                    assert!(matches!(err, PlanError::Unreachable(_)), "{context}");
+                    assert_eq!(expected, None, "{context}");
+                }
+            }
+        }
+    }
+}
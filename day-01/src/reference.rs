@@ -0,0 +1,105 @@
+//! The slow, obvious way to solve the puzzle: move the dial one click at a time
+//!
+//! Only plain turns, one per line, are read here. [`generate`](crate::generate) only writes plain
+//! turns too, so the differential check doesn't cover the rest of a [`script`](crate::script).
+
+use crate::{Turn, parse};
+use common::ParseError;
+
+struct Dial {
+    positions: u16,
+    position: u16,
+    wraps: u64,
+}
+
+impl Dial {
+    fn click(&mut self, turn: Turn) {
+        match turn {
+            Turn::Left(_) => {
+                if self.position == 0 {
+                    self.position = self.positions - 1;
+                    self.wraps += 1;
+                } else {
+                    self.position -= 1;
+                }
+            }
+            Turn::Right(_) => {
+                if self.position == self.positions - 1 {
+                    self.position = 0;
+                    self.wraps += 1;
+                } else {
+                    self.position += 1;
+                }
+            }
+        }
+    }
+
+    fn turn(&mut self, turn: Turn) {
+        let (Turn::Left(clicks) | Turn::Right(clicks)) = turn;
+        for _ in 0..clicks {
+            self.click(turn);
+        }
+    }
+}
+
+/// Count how many turns leave the dial pointing at zero
+pub fn part_1(input: &str) -> Result<u64, ParseError> {
+    part_1_with_dial(input, 100, 50)
+}
+
+/// Count how many turns leave a dial of `positions` positions, starting at `start`, pointing at zero
+pub fn part_1_with_dial(input: &str, positions: u16, start: u16) -> Result<u64, ParseError> {
+    let mut dial = Dial {
+        positions,
+        position: start,
+        wraps: 0,
+    };
+    let mut count = 0;
+    for turn in parse(input)? {
+        dial.turn(turn);
+        if dial.position == 0 {
+            count += 1;
+        }
+    }
+    Ok(count)
+}
+
+/// Count how many times the dial wraps around between 99 and 0
+pub fn part_2(input: &str) -> Result<u64, ParseError> {
+    part_2_with_dial(input, 100, 50)
+}
+
+/// Count how many times a dial of `positions` positions, starting at `start`, wraps around
+pub fn part_2_with_dial(input: &str, positions: u16, start: u16) -> Result<u64, ParseError> {
+    let mut dial = Dial {
+        positions,
+        position: start,
+        wraps: 0,
+    };
+    for turn in parse(input)? {
+        dial.turn(turn);
+    }
+    Ok(dial.wraps)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day01;
+    use common::{Part, differential};
+
+    #[test]
+    fn test_example() {
+        let input = include_str!("example.txt");
+        assert_eq!(part_1(input), Ok(3));
+        assert_eq!(part_2(input), Ok(6));
+    }
+
+    #[test]
+    fn test_differential() {
+        for part in Part::ALL {
+            let disagreement = differential::check(&Day01, part, 0..50, 100);
+            assert!(disagreement.is_none(), "{disagreement:?}");
+        }
+    }
+}
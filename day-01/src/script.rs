@@ -0,0 +1,409 @@
+//! A richer way to write down turns than one per line
+//!
+//! A script is a list of steps separated by spaces or new lines. Each step is one of:
+//!
+//! - a turn, like `L68` or `R48`
+//! - an absolute move, like `@25`, which turns the shortest way to 25, or `L@25` and `R@25`, which
+//!   turn that way until the dial points at 25
+//! - a repeat, like `3x(R10 L5)`, which does the steps in the brackets that many times
+//!
+//! Anything after a `#` on a line is a comment. The puzzle input is a script too.
+
+use crate::{Lock, Turn};
+use common::ParseError;
+
+/// Parse a script for the dial of `lock`
+///
+/// The whole script is checked up front, but the turns are only worked out as they are iterated
+/// over, so a big repeat doesn't take up any more memory than a small one. Absolute moves depend
+/// on where the dial is pointing, so they are worked out starting from the current position of
+/// `lock`.
+pub fn parse<'a>(input: &'a str, lock: &Lock) -> Result<Script<'a>, ParseError> {
+    let tokens = tokenize(input);
+    let mut tokens = tokens.iter().copied().peekable();
+    let mut parser = Parser {
+        input,
+        positions: lock.positions(),
+        steps: Vec::new(),
+    };
+    parser.steps(&mut tokens)?;
+    if let Some(token) = tokens.next() {
+        return Err(ParseError::new(token, "unmatched closing bracket").within(input, token));
+    }
+
+    Ok(Script {
+        input,
+        steps: parser.steps,
+        positions: lock.positions(),
+        start: lock.current(),
+    })
+}
+
+/// A parsed script, made by [`parse`]
+pub struct Script<'a> {
+    input: &'a str,
+    steps: Vec<Step<'a>>,
+    positions: u16,
+    start: u16,
+}
+
+impl<'a> Script<'a> {
+    /// The text the script was parsed from
+    pub fn input(&self) -> &'a str {
+        self.input
+    }
+
+    /// A fresh copy of the lock the script was parsed for, before any clicks were counted
+    pub fn lock(&self) -> Lock {
+        Lock::builder()
+            .positions(self.positions)
+            .start(self.start)
+            .build()
+    }
+
+    /// The turns the script makes, each with the text in the input that it came from
+    pub fn turns(&self) -> Turns<'_, 'a> {
+        Turns {
+            steps: &self.steps,
+            next: 0,
+            repeats: Vec::new(),
+            positions: self.positions as i128,
+            position: self.start as i128,
+        }
+    }
+}
+
+/// Split a script into words and brackets, leaving out spaces and comments
+fn tokenize(input: &str) -> Vec<&str> {
+    let mut tokens = Vec::new();
+    for line in input.lines() {
+        let line = match line.find('#') {
+            Some(comment) => &line[..comment],
+            None => line,
+        };
+        let mut rest = line;
+        while let Some(start) = rest.find(|c: char| !c.is_whitespace()) {
+            rest = &rest[start..];
+            let len = match rest.find(|c: char| c.is_whitespace() || c == '(' || c == ')') {
+                Some(0) => 1,
+                Some(len) => len,
+                None => rest.len(),
+            };
+            tokens.push(&rest[..len]);
+            rest = &rest[len..];
+        }
+    }
+    tokens
+}
+
+/// The repeat count of a token like `3x`, if it is one
+fn repeat_count(token: &str) -> Option<&str> {
+    token
+        .strip_suffix('x')
+        .filter(|count| !count.is_empty() && count.bytes().all(|b| b.is_ascii_digit()))
+}
+
+/// Finds the places a script can be split into smaller scripts that expand the same way
+///
+/// Lines are fed in one at a time, and a split can go after any line that leaves no repeat open.
+#[derive(Default)]
+pub(crate) struct Splitter {
+    depth: usize,
+    counted: bool,
+}
+
+impl Splitter {
+    /// Take in the next line, returning whether the script can be split after it
+    pub(crate) fn line(&mut self, line: &str) -> bool {
+        for token in tokenize(line) {
+            match token {
+                "(" => self.depth += 1,
+                // An unmatched bracket is an error as soon as the script so far is expanded
+                ")" if self.depth == 0 => return true,
+                ")" => self.depth -= 1,
+                _ => {}
+            }
+            self.counted = repeat_count(token).is_some();
+        }
+        self.depth == 0 && !self.counted
+    }
+}
+
+/// A step, with the steps inside a repeat laid out straight after it
+enum Step<'a> {
+    Turn(Turn, &'a str),
+    Absolute {
+        way: Way,
+        target: u16,
+        text: &'a str,
+    },
+    Repeat {
+        count: usize,
+        /// How many of the steps after this one are inside the brackets
+        len: usize,
+    },
+}
+
+/// Which way an absolute move turns the dial
+#[derive(Copy, Clone)]
+enum Way {
+    Left,
+    Right,
+    Shortest,
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    positions: u16,
+    steps: Vec<Step<'a>>,
+}
+
+impl<'a> Parser<'a> {
+    /// Parse steps until the end of the script or a closing bracket
+    fn steps(
+        &mut self,
+        tokens: &mut std::iter::Peekable<impl Iterator<Item = &'a str>>,
+    ) -> Result<(), ParseError> {
+        while let Some(token) = tokens.next_if(|&token| token != ")") {
+            self.step(token, tokens)?;
+        }
+        Ok(())
+    }
+
+    fn step(
+        &mut self,
+        token: &'a str,
+        tokens: &mut std::iter::Peekable<impl Iterator<Item = &'a str>>,
+    ) -> Result<(), ParseError> {
+        let input = self.input;
+        let error = |text: &str, reason: &str| ParseError::new(text, reason).within(input, text);
+
+        if let Some(count) = repeat_count(token) {
+            let count = count
+                .parse()
+                .map_err(|_| error(count, "too many repeats"))?;
+            if tokens.next_if_eq(&"(").is_none() {
+                return Err(error(token, "expected a bracket after the repeat count"));
+            }
+            let repeat = self.steps.len();
+            self.steps.push(Step::Repeat { count, len: 0 });
+            self.steps(tokens)?;
+            if tokens.next_if_eq(&")").is_none() {
+                return Err(error(token, "repeat is missing its closing bracket"));
+            }
+            let len = self.steps.len() - repeat - 1;
+            self.steps[repeat] = Step::Repeat { count, len };
+            return Ok(());
+        }
+
+        let (way, rest) = match token.split_at_checked(1) {
+            Some(("L", rest)) => (Way::Left, rest),
+            Some(("R", rest)) => (Way::Right, rest),
+            _ => (Way::Shortest, token),
+        };
+        if let Some(target) = rest.strip_prefix('@') {
+            let target = target
+                .parse()
+                .map_err(|_| error(target, "expected a position to move to"))?;
+            if target >= self.positions {
+                return Err(error(token, "position is not on the dial"));
+            }
+            self.steps.push(Step::Absolute {
+                way,
+                target,
+                text: token,
+            });
+            return Ok(());
+        }
+
+        if let Way::Shortest = way {
+            return Err(error(
+                token,
+                "expected a turn like L68, a move like @25, or a repeat like 3x(R10 L5)",
+            ));
+        }
+        let turn = token
+            .parse()
+            .map_err(|err: ParseError| err.within(input, token))?;
+        self.steps.push(Step::Turn(turn, token));
+        Ok(())
+    }
+}
+
+/// The turns a script makes, worked out one at a time from its steps
+///
+/// Made by [`Script::turns`].
+pub struct Turns<'s, 'a> {
+    steps: &'s [Step<'a>],
+    next: usize,
+    /// The repeats that are running, innermost last
+    repeats: Vec<Repeat>,
+    positions: i128,
+    position: i128,
+}
+
+/// A repeat that is part of the way through its steps
+struct Repeat {
+    start: usize,
+    end: usize,
+    left: usize,
+}
+
+impl<'a> Iterator for Turns<'_, 'a> {
+    type Item = (Turn, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(repeat) = self.repeats.last_mut()
+                && self.next == repeat.end
+            {
+                repeat.left -= 1;
+                if repeat.left > 0 {
+                    self.next = repeat.start;
+                } else {
+                    self.repeats.pop();
+                }
+                continue;
+            }
+
+            let step = self.steps.get(self.next)?;
+            self.next += 1;
+            let (turn, text) = match *step {
+                Step::Turn(turn, text) => (turn, text),
+                Step::Absolute { way, target, text } => {
+                    let right = (target as i128 - self.position).rem_euclid(self.positions);
+                    let left = (self.position - target as i128).rem_euclid(self.positions);
+                    let turn = match way {
+                        Way::Left => Turn::Left(left as u64),
+                        Way::Right => Turn::Right(right as u64),
+                        Way::Shortest if left < right => Turn::Left(left as u64),
+                        Way::Shortest => Turn::Right(right as u64),
+                    };
+                    (turn, text)
+                }
+                Step::Repeat { count, len } => {
+                    if count == 0 || len == 0 {
+                        self.next += len;
+                    } else {
+                        self.repeats.push(Repeat {
+                            start: self.next,
+                            end: self.next + len,
+                            left: count,
+                        });
+                    }
+                    continue;
+                }
+            };
+
+            self.position = match turn {
+                Turn::Left(clicks) => self.position - clicks as i128,
+                Turn::Right(clicks) => self.position + clicks as i128,
+            }
+            .rem_euclid(self.positions);
+            return Some((turn, text));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Turn::{Left, Right};
+
+    fn turns(input: &str) -> Result<Vec<Turn>, ParseError> {
+        let script = parse(input, &Lock::new())?;
+        Ok(script.turns().map(|(turn, _)| turn).collect())
+    }
+
+    #[test]
+    fn test_plain() {
+        let input = include_str!("example.txt");
+        assert_eq!(turns(input).unwrap(), crate::parse(input).unwrap());
+    }
+
+    #[test]
+    fn test_comments_and_blank_lines() {
+        let input = "# opening\n\nL68   # first\n  R48\n\n";
+        assert_eq!(turns(input), Ok(vec![Left(68), Right(48)]));
+    }
+
+    #[test]
+    fn test_repeat() {
+        assert_eq!(
+            turns("3x(R10 L5)"),
+            Ok(vec![
+                Right(10),
+                Left(5),
+                Right(10),
+                Left(5),
+                Right(10),
+                Left(5)
+            ])
+        );
+        assert_eq!(
+            turns("L1 2x( 2x(R2)\nL3 ) R4"),
+            Ok(vec![
+                Left(1),
+                Right(2),
+                Right(2),
+                Left(3),
+                Right(2),
+                Right(2),
+                Left(3),
+                Right(4)
+            ])
+        );
+        assert_eq!(turns("0x(R1) 5x()"), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_absolute() {
+        assert_eq!(turns("@25"), Ok(vec![Left(25)]));
+        assert_eq!(turns("@99 @25"), Ok(vec![Right(49), Right(26)]));
+        assert_eq!(
+            turns("R@25 L@75 @50"),
+            Ok(vec![Right(75), Left(50), Left(25)])
+        );
+        assert_eq!(turns("@0 @0"), Ok(vec![Right(50), Right(0)]));
+        assert_eq!(
+            turns("2x(R10 @0)"),
+            Ok(vec![Right(10), Right(40), Right(10), Left(10)])
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let check = |input: &str, line, column, text: &str| {
+            let err = turns(input).unwrap_err();
+            assert_eq!(
+                (err.line(), err.column(), err.text()),
+                (line, column, text),
+                "{err}"
+            );
+        };
+        check("L68\nX4", 2, 1, "X4");
+        check("L68 R4x", 1, 6, "4x");
+        check("@100", 1, 1, "@100");
+        check("L@x", 1, 3, "x");
+        check("3x R1", 1, 1, "3x");
+        check("3x(R1", 1, 1, "3x");
+        check("R1)", 1, 3, ")");
+        check("99999999999999999999x(R1)", 1, 1, "99999999999999999999");
+    }
+
+    #[test]
+    fn test_lazy() {
+        let input = "L1\n".repeat(1_200_000);
+        let script = parse(&input, &Lock::new()).unwrap();
+        assert_eq!(script.turns().count(), 1_200_000);
+
+        let input = "# fine\n  100000x(100000x(R1 @0))";
+        let script = parse(input, &Lock::new()).unwrap();
+        let first = script
+            .turns()
+            .take(4)
+            .map(|(turn, _)| turn)
+            .collect::<Vec<_>>();
+        assert_eq!(first, [Right(1), Right(49), Right(1), Left(1)]);
+    }
+}
@@ -0,0 +1,166 @@
+use crate::{Event, Turn};
+
+/// Figures about a whole sequence of turns, built up one [`Event`] at a time
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Stats {
+    landings: Vec<u64>,
+    turns: usize,
+    left: u128,
+    right: u128,
+    stretch: Stretch,
+    longest: Stretch,
+    crossings: Vec<(usize, u64)>,
+}
+
+/// A run of consecutive turns, none of which left the dial on zero
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Stretch {
+    /// The first turn of the run, counting from 1
+    pub start: usize,
+    /// How many turns are in the run
+    pub len: usize,
+}
+
+impl Stats {
+    /// Empty stats for a dial with `positions` positions
+    pub fn new(positions: u16) -> Self {
+        Self {
+            landings: vec![0; positions as usize],
+            turns: 0,
+            left: 0,
+            right: 0,
+            stretch: Stretch::default(),
+            longest: Stretch::default(),
+            crossings: Vec::new(),
+        }
+    }
+
+    /// Count the next turn
+    pub fn add(&mut self, event: &Event) {
+        self.turns += 1;
+        self.landings[event.end as usize] += 1;
+        match event.turn {
+            Turn::Left(clicks) => self.left += clicks as u128,
+            Turn::Right(clicks) => self.right += clicks as u128,
+        }
+
+        if event.landed_on_zero() {
+            self.stretch = Stretch::default();
+        } else {
+            if self.stretch.len == 0 {
+                self.stretch.start = self.turns;
+            }
+            self.stretch.len += 1;
+            if self.stretch.len > self.longest.len {
+                self.longest = self.stretch;
+            }
+        }
+
+        if event.crossings > 0 {
+            self.crossings.push((self.turns, event.crossings));
+        }
+    }
+
+    /// How many turns have been counted
+    pub fn turns(&self) -> usize {
+        self.turns
+    }
+
+    /// How many turns left the dial at each position
+    pub fn landings(&self) -> &[u64] {
+        &self.landings
+    }
+
+    /// The `count` positions the dial was left at most often, most often first
+    ///
+    /// Positions that were never landed on are left out, and ties go to the lower position.
+    pub fn most_visited(&self, count: usize) -> Vec<(u16, u64)> {
+        let mut visited = self
+            .landings
+            .iter()
+            .enumerate()
+            .filter(|&(_, &landings)| landings > 0)
+            .map(|(position, &landings)| (position as u16, landings))
+            .collect::<Vec<_>>();
+        visited.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        visited.truncate(count);
+        visited
+    }
+
+    /// The longest run of turns that never left the dial on zero, the earliest if there is a tie
+    pub fn longest_without_zero(&self) -> Stretch {
+        self.longest
+    }
+
+    /// The total clicks turned left
+    pub fn left(&self) -> u128 {
+        self.left
+    }
+
+    /// The total clicks turned right
+    pub fn right(&self) -> u128 {
+        self.right
+    }
+
+    /// Each turn that passed zero, counting from 1, with how many times it passed it
+    pub fn crossings(&self) -> &[(usize, u64)] {
+        &self.crossings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Lock, trace};
+
+    #[test]
+    fn test_example() {
+        let input = include_str!("example.txt");
+        let mut stats = Stats::new(100);
+        for event in trace(input, Lock::new()).unwrap() {
+            stats.add(&event);
+        }
+
+        assert_eq!(stats.turns(), 10);
+        assert_eq!(stats.landings().iter().sum::<u64>(), 10);
+        assert_eq!(stats.most_visited(2), [(0, 3), (14, 1)]);
+        assert_eq!(stats.left(), 68 + 30 + 5 + 55 + 1 + 99 + 82);
+        assert_eq!(stats.right(), 48 + 60 + 14);
+        assert_eq!(stats.longest_without_zero(), Stretch { start: 1, len: 2 });
+        assert_eq!(
+            stats.crossings(),
+            [(1, 1), (3, 1), (4, 1), (5, 1), (7, 1), (10, 1)]
+        );
+
+        let crossings = stats.crossings().iter().map(|&(_, n)| n).sum::<u64>();
+        assert_eq!(crossings, crate::part_2(input).unwrap());
+    }
+
+    #[test]
+    fn test_longest_stretch() {
+        let mut stats = Stats::new(10);
+        let landings: [u16; 10] = [1, 0, 2, 3, 4, 0, 5, 6, 7, 8];
+        let mut start = 5;
+        for end in landings {
+            let turn = Turn::Right(((end + 10 - start) % 10) as u64);
+            stats.add(&Event {
+                turn,
+                start,
+                end,
+                crossings: 0,
+            });
+            start = end;
+        }
+        assert_eq!(stats.longest_without_zero(), Stretch { start: 7, len: 4 });
+        assert_eq!(stats.most_visited(10).len(), 9);
+    }
+
+    #[test]
+    fn test_empty() {
+        let stats = Stats::new(10);
+        assert_eq!(stats.turns(), 0);
+        assert_eq!(stats.most_visited(3), []);
+        assert_eq!(stats.longest_without_zero(), Stretch::default());
+        assert_eq!(stats.crossings(), []);
+    }
+}
@@ -0,0 +1,183 @@
+use crate::{Lock, script, script::Script};
+use common::ParseError;
+use std::{error::Error, fmt, io, io::BufRead};
+
+/// The answers to both parts of the puzzle
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Answers {
+    /// How many turns left the dial pointing at zero
+    pub part_1: u64,
+    /// How many times the dial passed zero
+    pub part_2: u64,
+}
+
+/// Solve both parts of a [`script`] in one pass over `reader`
+///
+/// Only the lines of the step being read are held on to, so a plain list of turns is read a line
+/// at a time, and a repeat that spans several lines is read until its closing bracket.
+pub fn solve_reader(mut reader: impl BufRead, mut lock: Lock) -> Result<Answers, StreamError> {
+    let mut answers = Answers::default();
+    let mut splitter = script::Splitter::default();
+    let mut lines = String::new();
+    let mut first = 1;
+    let mut number = 0;
+
+    loop {
+        let start = lines.len();
+        let end = reader.read_line(&mut lines)? == 0;
+        number += 1;
+        if !end && !splitter.line(&lines[start..]) {
+            continue;
+        }
+
+        // Errors are positioned within the lines, so move them to the right line of the whole input
+        let relocate = |err: ParseError| {
+            let (line, column) = (err.line(), err.column());
+            err.at(first + line - 1, column)
+        };
+        let script = script::parse(&lines, &lock).map_err(relocate)?;
+        run(&script, &mut lock, &mut answers).map_err(relocate)?;
+        if end {
+            break;
+        }
+        lines.clear();
+        first = number + 1;
+    }
+
+    answers.part_2 = lock.clicks();
+    Ok(answers)
+}
+
+/// Turn `lock` with each of the turns of `script`, counting the ones that leave it pointing at zero
+pub(crate) fn run(
+    script: &Script,
+    lock: &mut Lock,
+    answers: &mut Answers,
+) -> Result<(), ParseError> {
+    let input = script.input();
+    for (turn, text) in script.turns() {
+        lock.turn(turn)
+            .map_err(|err| ParseError::new(text, err.to_string()).within(input, text))?;
+        if lock.current() == 0 {
+            answers.part_1 += 1;
+        }
+    }
+    Ok(())
+}
+
+/// Why [`solve_reader`] couldn't finish
+#[derive(Debug)]
+pub enum StreamError {
+    /// The turns couldn't be read
+    Io(io::Error),
+    /// The turns could be read, but they didn't make sense
+    Parse(ParseError),
+}
+
+impl From<io::Error> for StreamError {
+    fn from(err: io::Error) -> Self {
+        StreamError::Io(err)
+    }
+}
+
+impl From<ParseError> for StreamError {
+    fn from(err: ParseError) -> Self {
+        StreamError::Parse(err)
+    }
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StreamError::Io(err) => write!(f, "could not read the turns: {err}"),
+            StreamError::Parse(err) => err.fmt(f),
+        }
+    }
+}
+
+impl Error for StreamError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{GeneratorConfig, generate};
+    use std::io::{BufReader, Read};
+
+    fn solve(input: &str) -> Result<Answers, StreamError> {
+        solve_reader(input.as_bytes(), Lock::new())
+    }
+
+    #[test]
+    fn test_matches_trace() {
+        let inputs = [
+            include_str!("example.txt").to_string(),
+            generate(7, &GeneratorConfig::with_size(5000)),
+            "# a script\n\n3x(R10 L5) @0\nL@99\n".to_string(),
+            "L1 2x(\n  R2 # the first\n  2x\n(L3)\n) @0\n".to_string(),
+        ];
+        for input in inputs {
+            // Tracing expands the whole script at once, rather than a few lines at a time
+            let events = crate::trace(&input, Lock::new()).unwrap();
+            let landed = events.iter().filter(|event| event.landed_on_zero()).count();
+            let crossings = events.iter().map(|event| event.crossings).sum();
+            let answers = solve(&input).unwrap();
+            assert_eq!(answers.part_1, landed as u64);
+            assert_eq!(answers.part_2, crossings);
+        }
+    }
+
+    #[test]
+    fn test_small_reads() {
+        // A reader that hands over a byte at a time still sees whole lines
+        struct Trickle<'a>(&'a [u8]);
+        impl Read for Trickle<'_> {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                let Some((&first, rest)) = self.0.split_first() else {
+                    return Ok(0);
+                };
+                buf[0] = first;
+                self.0 = rest;
+                Ok(1)
+            }
+        }
+
+        let input = include_str!("example.txt");
+        let reader = BufReader::with_capacity(1, Trickle(input.as_bytes()));
+        let answers = solve_reader(reader, Lock::new()).unwrap();
+        assert_eq!(
+            answers,
+            Answers {
+                part_1: 3,
+                part_2: 6
+            }
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let Err(StreamError::Parse(err)) = solve("L68\nL30\nR4x\n") else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((err.line(), err.column(), err.text()), (3, 2, "4x"));
+
+        let Err(StreamError::Parse(err)) = solve("L68\n2x(\nR1\nL@x)\n") else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((err.line(), err.column(), err.text()), (4, 3, "x"));
+
+        let Err(StreamError::Parse(err)) = solve("L68\n2x(\nR1\n") else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((err.line(), err.column(), err.text()), (2, 1, "2x"));
+
+        let lock = Lock::builder().positions(1).start(0).build();
+        let input = "R18446744073709551615\nR1 L1\n";
+        let Err(StreamError::Parse(err)) = solve_reader(input.as_bytes(), lock) else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((err.line(), err.column(), err.text()), (2, 1, "R1"));
+
+        let err = solve_reader(&b"L68\n\xff\n"[..], Lock::new()).unwrap_err();
+        assert!(matches!(err, StreamError::Io(err) if err.kind() == io::ErrorKind::InvalidData));
+    }
+}
@@ -0,0 +1,82 @@
+use common::ParseError;
+use std::{
+    fmt,
+    num::{IntErrorKind, ParseIntError},
+    str::FromStr,
+};
+
+/// A single turn of the dial, written as `L68` or `R48`
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Turn {
+    /// Turn towards lower numbers
+    Left(u64),
+    /// Turn towards higher numbers
+    Right(u64),
+}
+
+impl fmt::Display for Turn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Turn::Left(clicks) => write!(f, "L{clicks}"),
+            Turn::Right(clicks) => write!(f, "R{clicks}"),
+        }
+    }
+}
+
+impl FromStr for Turn {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(left) = s.strip_prefix("L") {
+            Ok(Turn::Left(parse_clicks(left)?))
+        } else if let Some(right) = s.strip_prefix("R") {
+            Ok(Turn::Right(parse_clicks(right)?))
+        } else {
+            Err(ParseError::new(s, "expected a turn starting with L or R"))
+        }
+    }
+}
+
+fn parse_clicks(s: &str) -> Result<u64, ParseError> {
+    s.parse().map_err(|err: ParseIntError| {
+        let reason = match err.kind() {
+            IntErrorKind::PosOverflow => "too many clicks for one turn",
+            _ => "expected a number of clicks",
+        };
+        ParseError::new(s, reason).at(1, 2)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Turn::Left(68).to_string(), "L68");
+        assert_eq!(Turn::Right(0).to_string(), "R0");
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("L68".parse(), Ok(Turn::Left(68)));
+        assert_eq!("R48".parse(), Ok(Turn::Right(48)));
+
+        let err = "X48".parse::<Turn>().unwrap_err();
+        assert_eq!((err.column(), err.text()), (1, "X48"));
+        let err = "L".parse::<Turn>().unwrap_err();
+        assert_eq!((err.column(), err.text()), (2, ""));
+        let err = "R4x".parse::<Turn>().unwrap_err();
+        assert_eq!((err.column(), err.text()), (2, "4x"));
+    }
+
+    #[test]
+    fn test_parse_wide() {
+        assert_eq!("L40000".parse(), Ok(Turn::Left(40000)));
+        assert_eq!("R18446744073709551615".parse(), Ok(Turn::Right(u64::MAX)));
+
+        let err = "R18446744073709551616".parse::<Turn>().unwrap_err();
+        assert_eq!(err.reason(), "too many clicks for one turn");
+        assert_eq!((err.column(), err.text()), (2, "18446744073709551616"));
+    }
+}
@@ -0,0 +1,95 @@
+use crate::Range;
+use common::Rng;
+
+/// The shape of a generated list of ranges
+#[derive(Clone, Debug)]
+pub struct GeneratorConfig {
+    /// How many ranges to write
+    pub ranges: usize,
+    /// The most digits a number in a range can have
+    pub max_digits: u32,
+    /// The most numbers a single range can cover
+    pub max_width: usize,
+}
+
+impl GeneratorConfig {
+    /// A config for `size` ranges
+    pub fn with_size(size: usize) -> Self {
+        Self {
+            ranges: size,
+            max_digits: 10,
+            max_width: 10_000,
+        }
+    }
+}
+
+/// Write comma separated ranges, one per line
+///
+/// The number of digits is picked before the number itself, so that short numbers show up as
+/// often as long ones. Some of the ranges start just below a power of ten, so that they cross from
+/// one number of digits to the next.
+///
+/// At least one range is always written, since an empty list doesn't parse. The number of digits
+/// is kept from 1 to 19 and a range always covers at least one number.
+pub fn generate(seed: u64, config: &GeneratorConfig) -> String {
+    let mut rng = Rng::new(seed);
+    let max_digits = config.max_digits.clamp(1, 19);
+    let max_width = config.max_width.max(1);
+    let max = 10_u64.pow(max_digits) - 1;
+    let mut ranges = Vec::new();
+    for _ in 0..config.ranges.max(1) {
+        let width = rng.range(0, max_width as u64 - 1);
+        let from = if max_digits > 1 && rng.chance(20) {
+            let power = 10_u64.pow(rng.range(1, max_digits as u64 - 1) as u32);
+            power.saturating_sub(width / 2).max(1)
+        } else {
+            let digits = rng.range(1, max_digits as u64) as u32;
+            rng.range(10_u64.pow(digits - 1), 10_u64.pow(digits) - 1)
+        };
+        let to = from.saturating_add(width).min(max);
+        ranges.push(Range::new(from as u128, to as u128).to_string());
+    }
+    ranges.join(",\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ranges(seed: u64, config: &GeneratorConfig) -> Vec<Range> {
+        crate::parse(&generate(seed, config)).unwrap()
+    }
+
+    #[test]
+    fn test_generate() {
+        let config = GeneratorConfig::with_size(40);
+        let ranges = ranges(3, &config);
+        assert_eq!(ranges.len(), 40);
+        for range in ranges {
+            assert!(range.iter().count() <= config.max_width);
+            assert!(*range.iter().end() < 10_u128.pow(config.max_digits));
+        }
+    }
+
+    #[test]
+    fn test_generate_edges() {
+        assert_eq!(ranges(3, &GeneratorConfig::with_size(0)).len(), 1);
+
+        let config = GeneratorConfig {
+            ranges: 20,
+            max_digits: 1,
+            max_width: 0,
+        };
+        for range in ranges(3, &config) {
+            assert_eq!(range.iter().count(), 1);
+            assert!(*range.iter().end() < 10);
+        }
+
+        let config = GeneratorConfig {
+            ranges: 20,
+            max_digits: 40,
+            max_width: usize::MAX,
+        };
+        assert_eq!(ranges(3, &config).len(), 20);
+    }
+}
@@ -0,0 +1,199 @@
+//! Day 2: finding silly product IDs in ranges
+
+use common::{Answer, ParseError, Parsed, Part, Solver};
+
+pub use generate::{GeneratorConfig, generate};
+pub use range::{Range, RangeRepeatedNumberIterator, RangeSillyNumberIterator};
+pub use repeated_number::{RepeatedNumber, RepeatedNumberInfiniteIterator};
+pub use silly_number::{SillyNumber, SillyNumberInfiniteIterator};
+pub use silly_number_part_two::{is_silly_number_part_two, is_silly_number_part_two_radix};
+
+mod generate;
+mod range;
+pub mod reference;
+mod repeated_number;
+mod silly_number;
+mod silly_number_part_two;
+
+/// The puzzle input
+pub const INPUT: &str = include_str!("input.txt");
+
+/// The [`Solver`] for day 2
+pub struct Day02;
+
+impl Solver for Day02 {
+    fn day(&self) -> u8 {
+        2
+    }
+
+    fn input(&self) -> &'static str {
+        INPUT
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Box<dyn Parsed + 'a>, ParseError> {
+        Ok(Box::new(Ranges::parse(input)?))
+    }
+
+    fn part_one(&self, input: &str) -> Result<Answer, ParseError> {
+        part_one(input).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, ParseError> {
+        part_two(input).map(Answer::from)
+    }
+
+    fn generate(&self, seed: u64, size: usize) -> String {
+        generate(seed, &GeneratorConfig::with_size(size))
+    }
+
+    fn reference(&self, part: Part, input: &str) -> Result<Answer, ParseError> {
+        match part {
+            Part::One => reference::part_one(input).map(Answer::from),
+            Part::Two => reference::part_two(input).map(Answer::from),
+        }
+    }
+}
+
+/// Sum every number in the ranges whose digits are one block repeated twice
+pub fn part_one(text: &str) -> Result<u128, ParseError> {
+    Ranges::parse(text)?.sum(Range::silly_sum)
+}
+
+/// Sum every number in the ranges whose digits are one block repeated at least twice
+pub fn part_two(text: &str) -> Result<u128, ParseError> {
+    Ranges::parse(text)?.sum(Range::silly_sum_part_two)
+}
+
+/// The parsed ranges, each with the text it was parsed from so that sums can point back at it
+struct Ranges<'a> {
+    text: &'a str,
+    ranges: Vec<(Range, &'a str)>,
+}
+
+impl<'a> Ranges<'a> {
+    fn parse(text: &'a str) -> Result<Self, ParseError> {
+        let ranges = ranges(text, 10).collect::<Result<_, _>>()?;
+        Ok(Ranges { text, ranges })
+    }
+
+    /// Add up `sum` over every range, pointing at the range where the total gets too big for a
+    /// `u128`
+    fn sum(&self, sum: impl Fn(Range) -> Option<u128>) -> Result<u128, ParseError> {
+        let mut total: u128 = 0;
+        for &(range, range_text) in &self.ranges {
+            total = sum(range)
+                .and_then(|sum| total.checked_add(sum))
+                .ok_or_else(|| {
+                    ParseError::new(range_text, "sum is too big to count")
+                        .within(self.text, range_text)
+                })?;
+        }
+        Ok(total)
+    }
+}
+
+impl Parsed for Ranges<'_> {
+    fn part_one(&self) -> Result<Answer, ParseError> {
+        self.sum(Range::silly_sum).map(Answer::from)
+    }
+
+    fn part_two(&self) -> Result<Answer, ParseError> {
+        self.sum(Range::silly_sum_part_two).map(Answer::from)
+    }
+}
+
+/// Parse a comma separated list of ranges
+pub fn parse(text: &str) -> Result<Vec<Range>, ParseError> {
+    parse_radix(text, 10)
+}
+
+/// Parse a comma separated list of ranges written in `radix`
+///
+/// Panics if `radix` isn't from 2 to 36.
+pub fn parse_radix(text: &str, radix: u32) -> Result<Vec<Range>, ParseError> {
+    ranges(text, radix)
+        .map(|range| range.map(|(range, _)| range))
+        .collect()
+}
+
+/// Each range in a comma separated list, with the text it was parsed from
+fn ranges(text: &str, radix: u32) -> impl Iterator<Item = Result<(Range, &str), ParseError>> {
+    text.trim().split(",").map(move |range| {
+        let range = range.trim();
+        Range::from_str_radix(range, radix)
+            .map(|parsed| (parsed, range))
+            .map_err(|err| err.within(text, range))
+    })
+}
+
+/// How many digits `n` has when it's written in `radix`
+fn digits(n: u128, radix: u32) -> u32 {
+    n.checked_ilog(radix as u128).unwrap_or(0) + 1
+}
+
+/// `n` written in `radix`, using lowercase letters for the digits past 9
+fn to_string_radix(mut n: u128, radix: u32) -> String {
+    let mut digits = Vec::new();
+    loop {
+        digits.push(char::from_digit((n % radix as u128) as u32, radix).unwrap());
+        n /= radix as u128;
+        if n == 0 {
+            break;
+        }
+    }
+    digits.iter().rev().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let example = include_str!("example.txt");
+        let output = part_one(example).unwrap();
+        assert_eq!(output, 1227775554);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let example = include_str!("example.txt");
+        let output = part_two(example).unwrap();
+        assert_eq!(output, 4174379265);
+    }
+
+    #[test]
+    fn test_parse_radix() {
+        let ranges = parse_radix("b-16, 1010-1100", 2).unwrap_err();
+        assert_eq!(ranges.text(), "b");
+        let ranges = parse_radix("1011-10110, 1010-1100", 2).unwrap();
+        assert_eq!(
+            ranges,
+            [Range::new_radix(11, 22, 2), Range::new_radix(10, 12, 2)]
+        );
+        let ranges = parse_radix("a-FF", 16).unwrap();
+        assert_eq!(ranges, [Range::new_radix(10, 255, 16)]);
+        assert_eq!(ranges[0].to_string(), "a-ff");
+        assert_eq!(to_string_radix(0, 36), "0");
+        assert_eq!(to_string_radix(36 * 36 - 1, 36), "zz");
+    }
+
+    #[test]
+    fn test_too_big() {
+        let nines = "9".repeat(38);
+        let input = format!("{nines}-{nines},\n{nines}-{nines}");
+        assert_eq!(part_one(&input), Ok(2 * nines.parse::<u128>().unwrap()));
+
+        let input = format!("{input},{input}");
+        let err = part_two(&input).unwrap_err();
+        assert_eq!((err.line(), err.column()), (3, 1));
+        assert_eq!(err.reason(), "sum is too big to count");
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = part_one("11-22,95-115,\n998-10x2").unwrap_err();
+        assert_eq!((err.line(), err.column()), (2, 5));
+        assert_eq!(err.text(), "10x2");
+    }
+}
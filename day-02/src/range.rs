@@ -0,0 +1,575 @@
+use crate::{RepeatedNumber, SillyNumber, digits, to_string_radix};
+use common::ParseError;
+use std::{fmt, iter, ops::RangeInclusive, str::FromStr};
+
+/// An inclusive range of product IDs, written as `11-22`
+///
+/// The IDs are decimal unless the range is made with one of the `_radix` functions, in which case
+/// the silly numbers in it are the ones that are silly when written in that radix.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Range {
+    from: u128,
+    to: u128,
+    radix: u32,
+}
+
+impl fmt::Display for Range {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}-{}",
+            to_string_radix(self.from, self.radix),
+            to_string_radix(self.to, self.radix)
+        )
+    }
+}
+
+impl FromStr for Range {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Range::from_str_radix(s, 10)
+    }
+}
+
+impl Range {
+    /// Create a range from `from` to `to`, inclusive
+    pub fn new(from: u128, to: u128) -> Self {
+        Self::new_radix(from, to, 10)
+    }
+
+    /// Create a range from `from` to `to`, inclusive, of IDs written in `radix`
+    ///
+    /// Panics if `radix` isn't from 2 to 36.
+    pub fn new_radix(from: u128, to: u128, radix: u32) -> Self {
+        assert!((2..=36).contains(&radix), "radix must be from 2 to 36");
+
+        Self { from, to, radix }
+    }
+
+    /// Parse a range with both ends written in `radix`, like `a-ff` in hexadecimal
+    ///
+    /// Panics if `radix` isn't from 2 to 36.
+    pub fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseError> {
+        let (from, to) = s
+            .split_once("-")
+            .ok_or_else(|| ParseError::new(s, "expected a range like 11-22"))?;
+        let from = u128::from_str_radix(from, radix)
+            .map_err(|_| ParseError::new(from, "expected a number"))?;
+        let to = u128::from_str_radix(to, radix)
+            .map_err(|_| ParseError::new(to, "expected a number").within(s, to))?;
+        Ok(Range::new_radix(from, to, radix))
+    }
+
+    /// The radix the IDs are written in
+    pub fn radix(self) -> u32 {
+        self.radix
+    }
+
+    /// Iterate over the values of the silly numbers within the range
+    pub fn silly_numbers(self) -> RangeSillyNumberIterator {
+        RangeSillyNumberIterator::new_radix(self.from, self.to, self.radix)
+    }
+
+    /// Iterate over the values of the numbers within the range whose digits are one block written
+    /// `repetitions` times
+    ///
+    /// Panics if `repetitions` is zero.
+    pub fn repeated_numbers(self, repetitions: u32) -> RangeRepeatedNumberIterator {
+        RangeRepeatedNumberIterator::new_radix(self.from, self.to, repetitions, self.radix)
+    }
+
+    /// Iterate over the values of the numbers within the range whose digits are one block written
+    /// two or more times
+    ///
+    /// Only the candidates for each number of repetitions are looked at, so this takes time in
+    /// proportion to how many there are rather than how wide the range is. Each number comes up
+    /// once, but not in order.
+    pub fn repeated_numbers_at_least_twice(self) -> impl Iterator<Item = u128> {
+        let Range { from, to, radix } = self;
+        let most = digits(to, radix);
+        // Zero is zero however many times it's written, so it gets counted on its own
+        let zero = (from == 0).then_some(0);
+        let rest = (2..=most).flat_map(move |repetitions| {
+            let first = RepeatedNumber::next_radix(from.max(1), repetitions, radix);
+            iter::successors(first, |number| number.successor())
+                .take_while(move |number| number.value() <= to)
+                .filter(|number| number.is_primitive())
+                .map(RepeatedNumber::value)
+        });
+        zero.into_iter().chain(rest)
+    }
+
+    /// How many silly numbers are within the range
+    ///
+    /// This is worked out a whole digit length at a time rather than by looking at each number.
+    pub fn silly_count(self) -> u128 {
+        // Zero is silly too, however many times it's written
+        let zero = (self.from == 0) as u128;
+        zero + self.silly_bands().map(|band| band.count).sum::<u128>()
+    }
+
+    /// The sum of the silly numbers within the range, or `None` if it's too big for a `u128`
+    ///
+    /// Silly numbers with `2d` digits are the bases with `d` digits times `radix^d + 1`, so each digit
+    /// length adds up as an arithmetic series.
+    pub fn silly_sum(self) -> Option<u128> {
+        self.silly_bands()
+            .try_fold(0_u128, |total, band| total.checked_add(band.sum?))
+    }
+
+    /// How many numbers within the range have digits that are one block written two or more times
+    ///
+    /// Like [`Range::silly_count`] this goes a digit length at a time, see
+    /// [`Range::silly_sum_part_two`] for how.
+    pub fn silly_count_part_two(self) -> u128 {
+        let zero = (self.from == 0) as u128;
+        let (added, taken) = self
+            .part_two_bands()
+            .fold((0, 0), |(added, taken), band| match band.sign {
+                Sign::Add => (added + band.count, taken),
+                Sign::Take => (added, taken + band.count),
+            });
+        zero + added - taken
+    }
+
+    /// The sum of the numbers within the range whose digits are one block written two or more
+    /// times, or `None` if it's too big to work out in a `u128`
+    ///
+    /// A number with `n` digits that repeats a block of `b` digits also repeats a block of any
+    /// multiple of `b` that divides `n`. So every such number repeats a block of `n / p` digits for
+    /// some prime `p` dividing `n`, and adding up those sets for each prime while taking away their
+    /// overlaps (inclusion–exclusion) counts each number once. The sets that are added up can be
+    /// bigger than the answer, so this can give up on an answer just short of the limit.
+    pub fn silly_sum_part_two(self) -> Option<u128> {
+        let (added, taken) = self.part_two_bands().try_fold(
+            (0_u128, 0_u128),
+            |(added, taken), band| match band.sign {
+                Sign::Add => Some((added.checked_add(band.sum?)?, taken)),
+                Sign::Take => Some((added, taken.checked_add(band.sum?)?)),
+            },
+        )?;
+        Some(added - taken)
+    }
+
+    /// The silly numbers in the range for each digit length
+    fn silly_bands(self) -> impl Iterator<Item = Band> {
+        let most = digits(u128::MAX, self.radix);
+        (1..=most / 2).map(move |block| self.band(block * 2, block, Sign::Add))
+    }
+
+    /// The part two silly numbers in the range for each digit length and each term of the
+    /// inclusion–exclusion
+    fn part_two_bands(self) -> impl Iterator<Item = Band> {
+        let most = digits(u128::MAX, self.radix);
+        (2..=most).flat_map(move |digits| {
+            let primes = prime_factors(digits);
+            (1..1_u32 << primes.len()).map(move |subset| {
+                let (product, len) = primes
+                    .iter()
+                    .enumerate()
+                    .filter(|&(i, _)| subset & (1 << i) != 0)
+                    .fold((1, 0), |(product, len), (_, p)| (product * p, len + 1));
+                let sign = if len % 2 == 1 { Sign::Add } else { Sign::Take };
+                self.band(digits, digits / product, sign)
+            })
+        })
+    }
+
+    /// The numbers in the range with `digits` digits that are a block of `block` digits written
+    /// over and over
+    fn band(self, digits: u32, block: u32, sign: Sign) -> Band {
+        let empty = Band {
+            sign,
+            count: 0,
+            sum: Some(0),
+        };
+        let radix = self.radix as u128;
+        let low = self.from.max(radix.pow(digits - 1));
+        let high = match radix.checked_pow(digits) {
+            Some(power) => self.to.min(power - 1),
+            None => self.to,
+        };
+        if low > high {
+            return empty;
+        }
+
+        // Writing out a block over and over multiplies it by something like 1001001. If that
+        // doesn't fit then neither does any number it makes.
+        let step = radix.pow(block);
+        let Some(multiplier) = (1..digits / block).try_fold(1_u128, |multiplier, _| {
+            multiplier.checked_mul(step)?.checked_add(1)
+        }) else {
+            return empty;
+        };
+        let first = low.div_ceil(multiplier).max(radix.pow(block - 1));
+        let last = (high / multiplier).min(step - 1);
+        if first > last {
+            return empty;
+        }
+
+        // Either the count or the sum of the ends is even, and halving that one first keeps the
+        // product from overflowing any sooner than it has to
+        let count = last - first + 1;
+        let (a, b) = if count % 2 == 0 {
+            (count / 2, first + last)
+        } else {
+            (count, (first + last) / 2)
+        };
+        Band {
+            sign,
+            count,
+            sum: a.checked_mul(b).and_then(|sum| sum.checked_mul(multiplier)),
+        }
+    }
+
+    /// Iterate over every number within the range
+    pub fn iter(self) -> RangeInclusive<u128> {
+        self.from..=self.to
+    }
+}
+
+/// The count and sum of some of the numbers in a range, and whether they are added to or taken
+/// away from the total
+struct Band {
+    sign: Sign,
+    count: u128,
+    sum: Option<u128>,
+}
+
+#[derive(Copy, Clone)]
+enum Sign {
+    Add,
+    Take,
+}
+
+/// The distinct primes that divide `n`
+fn prime_factors(mut n: u32) -> Vec<u32> {
+    let mut primes = Vec::new();
+    let mut p = 2;
+    while n > 1 {
+        if n.is_multiple_of(p) {
+            primes.push(p);
+            while n.is_multiple_of(p) {
+                n /= p;
+            }
+        }
+        p += 1;
+    }
+    primes
+}
+
+/// The values of the silly numbers within a range, see [`Range::silly_numbers`]
+pub struct RangeSillyNumberIterator {
+    next: Option<SillyNumber>,
+    stop: u128,
+}
+
+impl RangeSillyNumberIterator {
+    /// Iterate over the silly numbers from `from` to `to`, inclusive
+    pub fn new(from: u128, to: u128) -> Self {
+        Self::new_radix(from, to, 10)
+    }
+
+    /// Iterate over the numbers from `from` to `to`, inclusive, that are silly when written in
+    /// `radix`
+    ///
+    /// Panics if `radix` isn't from 2 to 36.
+    pub fn new_radix(from: u128, to: u128, radix: u32) -> Self {
+        let next = SillyNumber::next_radix(from, radix);
+        RangeSillyNumberIterator { next, stop: to }
+    }
+}
+
+impl Iterator for RangeSillyNumberIterator {
+    type Item = u128;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let v = self.next.filter(|v| v.value() <= self.stop)?;
+        self.next = v.successor();
+        Some(v.value())
+    }
+}
+
+/// The values of the repeated numbers within a range, see [`Range::repeated_numbers`]
+pub struct RangeRepeatedNumberIterator {
+    next: Option<RepeatedNumber>,
+    stop: u128,
+}
+
+impl RangeRepeatedNumberIterator {
+    /// Iterate over the numbers from `from` to `to`, inclusive, made of a block written
+    /// `repetitions` times
+    ///
+    /// Panics if `repetitions` is zero.
+    pub fn new(from: u128, to: u128, repetitions: u32) -> Self {
+        Self::new_radix(from, to, repetitions, 10)
+    }
+
+    /// Iterate over the numbers from `from` to `to`, inclusive, made of a block written
+    /// `repetitions` times in `radix`
+    ///
+    /// Panics if `repetitions` is zero or `radix` isn't from 2 to 36.
+    pub fn new_radix(from: u128, to: u128, repetitions: u32, radix: u32) -> Self {
+        let next = RepeatedNumber::next_radix(from, repetitions, radix);
+        RangeRepeatedNumberIterator { next, stop: to }
+    }
+}
+
+impl Iterator for RangeRepeatedNumberIterator {
+    type Item = u128;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let v = self.next.filter(|v| v.value() <= self.stop)?;
+        self.next = v.successor();
+        Some(v.value())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Rng;
+
+    #[test]
+    fn test_range() {
+        let range = Range::new(11, 22);
+        let numbers = range.silly_numbers().collect::<Vec<_>>();
+        assert_eq!(numbers, [11, 22]);
+
+        let range: Range = "95-115".parse().unwrap();
+        let numbers = range.silly_numbers().collect::<Vec<_>>();
+        assert_eq!(numbers, [99]);
+        let range: Range = "998-1012".parse().unwrap();
+        let numbers = range.silly_numbers().collect::<Vec<_>>();
+        assert_eq!(numbers, [1010]);
+        let range: Range = "1188511880-1188511890".parse().unwrap();
+        let numbers = range.silly_numbers().collect::<Vec<_>>();
+        assert_eq!(numbers, [1188511885]);
+        let range: Range = "222220-222224".parse().unwrap();
+        let numbers = range.silly_numbers().collect::<Vec<_>>();
+        assert_eq!(numbers, [222222]);
+        let range: Range = "1698522-1698528".parse().unwrap();
+        let numbers = range.silly_numbers().collect::<Vec<_>>();
+        assert_eq!(numbers, []);
+        let range: Range = "446443-446449".parse().unwrap();
+        let numbers = range.silly_numbers().collect::<Vec<_>>();
+        assert_eq!(numbers, [446446]);
+        let range: Range = "38593856-38593862".parse().unwrap();
+        let numbers = range.silly_numbers().collect::<Vec<_>>();
+        assert_eq!(numbers, [38593859]);
+    }
+
+    #[test]
+    fn test_repeated_numbers() {
+        let range: Range = "1-200000".parse().unwrap();
+        let silly = range.silly_numbers().collect::<Vec<_>>();
+        assert_eq!(range.repeated_numbers(2).collect::<Vec<_>>(), silly);
+
+        let range = Range::new(100, 1000);
+        let numbers = range.repeated_numbers(3).collect::<Vec<_>>();
+        assert_eq!(numbers, [111, 222, 333, 444, 555, 666, 777, 888, 999]);
+        let range: Range = "2121212118-2121212124".parse().unwrap();
+        let numbers = range.repeated_numbers(5).collect::<Vec<_>>();
+        assert_eq!(numbers, [2121212121]);
+        assert_eq!(range.repeated_numbers(2).count(), 0);
+    }
+
+    #[test]
+    fn test_repeated_numbers_at_least_twice() {
+        let numbers = |range: Range| {
+            let mut numbers = range.repeated_numbers_at_least_twice().collect::<Vec<_>>();
+            numbers.sort();
+            numbers
+        };
+        let brute_force = |range: Range| {
+            range
+                .iter()
+                .filter(|&n| crate::is_silly_number_part_two(n))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(numbers(Range::new(95, 115)), [99, 111]);
+        assert_eq!(numbers(Range::new(1111, 1111)), [1111]);
+        assert_eq!(numbers(Range::new(0, 22)), [0, 11, 22]);
+        assert_eq!(numbers(Range::new(0, 0)), [0]);
+        assert_eq!(numbers(Range::new(50, 40)), []);
+        for range in [
+            Range::new(0, 200_000),
+            Range::new(110_000, 112_000),
+            Range::new(99_999, 1_010_101),
+        ] {
+            assert_eq!(numbers(range), brute_force(range), "{range}");
+        }
+
+        // Far too wide to look at every number
+        let range = Range::new(1, 10_u128.pow(8));
+        let count = range.repeated_numbers_at_least_twice().count();
+        let mut union = std::collections::BTreeSet::new();
+        for repetitions in 2..=8 {
+            union.extend(range.repeated_numbers(repetitions));
+        }
+        assert_eq!(count, union.len());
+    }
+
+    #[test]
+    fn test_silly_sum() {
+        let range = Range::new(11, 22);
+        assert_eq!((range.silly_count(), range.silly_sum()), (2, Some(33)));
+        let range = Range::new(95, 115);
+        assert_eq!((range.silly_count(), range.silly_sum()), (1, Some(99)));
+        assert_eq!(
+            (range.silly_count_part_two(), range.silly_sum_part_two()),
+            (2, Some(99 + 111))
+        );
+        let range = Range::new(0, 0);
+        assert_eq!((range.silly_count(), range.silly_sum()), (1, Some(0)));
+        assert_eq!(range.silly_count_part_two(), 1);
+        let range = Range::new(50, 40);
+        assert_eq!((range.silly_count(), range.silly_sum()), (0, Some(0)));
+        assert_eq!(range.silly_count_part_two(), 0);
+
+        // Zero, then one block of up to nineteen digits: the next one doesn't fit in a u128
+        let all = Range::new(0, u128::MAX);
+        assert_eq!(all.silly_count(), 10_u128.pow(19));
+        assert_eq!(all.silly_sum(), None);
+        assert_eq!(all.silly_sum_part_two(), None);
+    }
+
+    #[test]
+    fn test_silly_sum_matches_iterating() {
+        let mut rng = Rng::new(23);
+        for _ in 0..500 {
+            let digits = rng.range(1, 8) as u32;
+            let from = rng.range(0, 10_u64.pow(digits)) as u128;
+            let width = 10_u64.pow(rng.range(0, 7) as u32);
+            let to = from + rng.range(0, width) as u128;
+            let range = Range::new(from, to);
+
+            let silly = range.silly_numbers().collect::<Vec<_>>();
+            assert_eq!(range.silly_count(), silly.len() as u128, "{range}");
+            assert_eq!(range.silly_sum(), Some(silly.iter().sum()), "{range}");
+            let part_two = range.repeated_numbers_at_least_twice().collect::<Vec<_>>();
+            assert_eq!(
+                range.silly_count_part_two(),
+                part_two.len() as u128,
+                "{range}"
+            );
+            assert_eq!(
+                range.silly_sum_part_two(),
+                Some(part_two.iter().sum()),
+                "{range}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_prime_factors() {
+        assert_eq!(prime_factors(1), []);
+        assert_eq!(prime_factors(7), [7]);
+        assert_eq!(prime_factors(12), [2, 3]);
+        assert_eq!(prime_factors(20), [2, 5]);
+    }
+
+    #[test]
+    fn test_radix() {
+        let range = Range::from_str_radix("1010-1100", 2).unwrap();
+        assert_eq!(range, Range::new_radix(10, 12, 2));
+        assert_eq!(range.silly_numbers().collect::<Vec<_>>(), [0b1010]);
+        assert_eq!(range.to_string(), "1010-1100");
+        let err = Range::from_str_radix("1010-1102", 2).unwrap_err();
+        assert_eq!((err.column(), err.text()), (6, "1102"));
+
+        for radix in [2, 3, 8, 16, 36] {
+            let range = Range::new_radix(0, 50_000, radix);
+            let brute_force = range
+                .iter()
+                .filter(|&n| crate::is_silly_number_part_two_radix(n, radix))
+                .collect::<Vec<_>>();
+            let mut numbers = range.repeated_numbers_at_least_twice().collect::<Vec<_>>();
+            numbers.sort();
+            assert_eq!(numbers, brute_force, "{radix}");
+            assert_eq!(
+                range.silly_count_part_two(),
+                brute_force.len() as u128,
+                "{radix}"
+            );
+            assert_eq!(
+                range.silly_sum_part_two(),
+                Some(brute_force.iter().sum()),
+                "{radix}"
+            );
+
+            let silly = range.silly_numbers().collect::<Vec<_>>();
+            assert_eq!(range.repeated_numbers(2).collect::<Vec<_>>(), silly);
+            assert_eq!(range.silly_count(), silly.len() as u128, "{radix}");
+            assert_eq!(range.silly_sum(), Some(silly.iter().sum()), "{radix}");
+        }
+
+        // Every silly number that fits in 64 bits: a block of 1 to 32 bits starting with a 1
+        let all = Range::new_radix(0, u64::MAX as u128, 2);
+        assert_eq!(all.silly_count(), 1 << 32);
+        let all = Range::new_radix(0, u128::MAX, 2);
+        assert_eq!(all.silly_count(), 1 << 64);
+    }
+
+    #[test]
+    fn test_top_of_u128() {
+        // 38 nines is the biggest decimal silly number, and the next one would have 40 digits
+        let nines = 10_u128.pow(38) - 1;
+        let range = Range::new(nines - 10, u128::MAX);
+        assert_eq!(range.silly_numbers().collect::<Vec<_>>(), [nines]);
+        assert_eq!(range.repeated_numbers(2).collect::<Vec<_>>(), [nines]);
+        assert_eq!(range.repeated_numbers(38).collect::<Vec<_>>(), [nines]);
+        assert_eq!((range.silly_count(), range.silly_sum()), (1, Some(nines)));
+
+        // 39 digits fit as long as they start with at most 3, so 39 ones, twos and threes do too
+        let range = Range::new(nines - 10, nines + 10);
+        assert_eq!(
+            range.repeated_numbers_at_least_twice().collect::<Vec<_>>(),
+            [nines]
+        );
+        let ones = nines / 9;
+        let range = Range::new(nines - 10, u128::MAX);
+        assert_eq!(
+            range.repeated_numbers(39).collect::<Vec<_>>(),
+            [ones * 10 + 1, ones * 20 + 2, ones * 30 + 3]
+        );
+        // The nines, then 39 digit numbers with blocks of 13 digits or 3 digits, both of which
+        // count the blocks of 1 digit
+        let thirteens = 3_402_823_669_209 - 10_u128.pow(12) + 1;
+        let threes = 339 - 100 + 1;
+        assert_eq!(range.silly_count_part_two(), 1 + thirteens + threes - 3);
+
+        // All 128 bits set is silly in binary, and is the very last u128
+        let range = Range::new_radix(u128::MAX - 1000, u128::MAX, 2);
+        let brute_force = range
+            .iter()
+            .filter(|&n| crate::is_silly_number_part_two_radix(n, 2))
+            .collect::<Vec<_>>();
+        let mut numbers = range.repeated_numbers_at_least_twice().collect::<Vec<_>>();
+        numbers.sort();
+        assert_eq!(numbers, brute_force);
+        assert_eq!(brute_force.last(), Some(&u128::MAX));
+        assert_eq!(range.silly_numbers().collect::<Vec<_>>(), [u128::MAX]);
+        assert_eq!(range.silly_sum(), Some(u128::MAX));
+        assert_eq!(range.silly_sum_part_two(), Some(u128::MAX));
+        assert_eq!(range.silly_count_part_two(), brute_force.len() as u128);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Range::new(95, 115).to_string(), "95-115");
+        assert_eq!("95-115".parse::<Range>().unwrap(), Range::new(95, 115));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = "95".parse::<Range>().unwrap_err();
+        assert_eq!((err.column(), err.text()), (1, "95"));
+        let err = "x-115".parse::<Range>().unwrap_err();
+        assert_eq!((err.column(), err.text()), (1, "x"));
+        let err = "95-11x".parse::<Range>().unwrap_err();
+        assert_eq!((err.column(), err.text()), (4, "11x"));
+    }
+}
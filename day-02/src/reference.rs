@@ -0,0 +1,62 @@
+//! The slow, obvious way to solve the puzzle: write out every number and look at its digits
+
+use crate::parse;
+use common::ParseError;
+
+/// Whether the digits are one block written `times` times
+fn repeats(digits: &str, times: usize) -> bool {
+    if !digits.len().is_multiple_of(times) {
+        return false;
+    }
+    let block = &digits[..digits.len() / times];
+    block.repeat(times) == digits
+}
+
+/// Sum every number in the ranges whose digits are one block repeated twice
+pub fn part_one(text: &str) -> Result<u128, ParseError> {
+    let mut sum = 0;
+    for range in parse(text)? {
+        for number in range.iter() {
+            if repeats(&number.to_string(), 2) {
+                sum += number;
+            }
+        }
+    }
+    Ok(sum)
+}
+
+/// Sum every number in the ranges whose digits are one block repeated at least twice
+pub fn part_two(text: &str) -> Result<u128, ParseError> {
+    let mut sum = 0;
+    for range in parse(text)? {
+        for number in range.iter() {
+            let digits = number.to_string();
+            if (2..=digits.len()).any(|times| repeats(&digits, times)) {
+                sum += number;
+            }
+        }
+    }
+    Ok(sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day02;
+    use common::{Part, differential};
+
+    #[test]
+    fn test_example() {
+        let input = include_str!("example.txt");
+        assert_eq!(part_one(input), Ok(1227775554));
+        assert_eq!(part_two(input), Ok(4174379265));
+    }
+
+    #[test]
+    fn test_differential() {
+        for part in Part::ALL {
+            let disagreement = differential::check(&Day02, part, 0..20, 10);
+            assert!(disagreement.is_none(), "{disagreement:?}");
+        }
+    }
+}
@@ -0,0 +1,339 @@
+use crate::digits;
+
+/// A number whose digits are one block of digits written some number of times
+///
+/// Like [`SillyNumber`](crate::SillyNumber), it is impossible to construct one of these that isn't
+/// made of a repeated block or whose value doesn't fit in a `u128`, but the number of repetitions
+/// can be anything, not just two. It is decimal too unless it's made with one of the `_radix`
+/// functions.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub struct RepeatedNumber {
+    radix: u32,
+    repetitions: u32,
+    base: u128,
+}
+
+impl RepeatedNumber {
+    /// The smallest number with `repetitions` repetitions, which is zero
+    ///
+    /// Panics if `repetitions` is zero.
+    pub const fn zero(repetitions: u32) -> Self {
+        Self::zero_radix(repetitions, 10)
+    }
+
+    /// The smallest number with `repetitions` repetitions written in `radix`, which is zero
+    ///
+    /// Panics if `repetitions` is zero or `radix` isn't from 2 to 36.
+    pub const fn zero_radix(repetitions: u32, radix: u32) -> Self {
+        assert!(repetitions > 0);
+        assert!(2 <= radix && radix <= 36, "radix must be from 2 to 36");
+
+        Self {
+            radix,
+            repetitions,
+            base: 0,
+        }
+    }
+
+    /// The number made from `base`, if its value fits
+    pub(crate) fn from_base(base: u128, repetitions: u32, radix: u32) -> Option<Self> {
+        let number = Self::zero_radix(repetitions, radix);
+        Self::checked_value(base, repetitions, radix)?;
+        Some(Self { base, ..number })
+    }
+
+    fn checked_value(base: u128, repetitions: u32, radix: u32) -> Option<u128> {
+        if base == 0 || repetitions == 1 {
+            // A single block is just the block, even when shifting it along would overflow
+            return Some(base);
+        }
+
+        let factor_of_radix = (radix as u128).checked_pow(digits(base, radix))?;
+        let mut value: u128 = 0;
+        for _ in 0..repetitions {
+            value = value.checked_mul(factor_of_radix)?.checked_add(base)?;
+        }
+        Some(value)
+    }
+
+    /// The block of digits that is repeated
+    pub fn base(self) -> u128 {
+        self.base
+    }
+
+    /// How many times the block is written
+    pub fn repetitions(self) -> u32 {
+        self.repetitions
+    }
+
+    /// The radix the number is written in
+    pub fn radix(self) -> u32 {
+        self.radix
+    }
+
+    /// Whether the block can't itself be split into a smaller block written several times
+    ///
+    /// A number like 111111 can be written with a block of 111, 11 or 1, and only the last of
+    /// those is primitive, so counting only primitive numbers counts each number once.
+    pub fn is_primitive(self) -> bool {
+        !crate::is_silly_number_part_two_radix(self.base, self.radix)
+    }
+
+    /// Get the value of the number
+    pub fn value(self) -> u128 {
+        // Only numbers whose value fits are ever made
+        Self::checked_value(self.base, self.repetitions, self.radix).unwrap()
+    }
+
+    /// Get the number with the same repetitions that follows this one, or `None` if its value is
+    /// too big for a `u128`
+    pub fn successor(self) -> Option<Self> {
+        Self::from_base(self.base.checked_add(1)?, self.repetitions, self.radix)
+    }
+
+    /// Get the next number with `repetitions` repetitions that has a value equal to or greater
+    /// than the provided value, or `None` if its value is too big for a `u128`
+    ///
+    /// Panics if `repetitions` is zero.
+    pub fn next(val: u128, repetitions: u32) -> Option<Self> {
+        Self::next_radix(val, repetitions, 10)
+    }
+
+    /// Get the next number with `repetitions` repetitions written in `radix` that has a value
+    /// equal to or greater than the provided value, or `None` if its value is too big for a `u128`
+    ///
+    /// Panics if `repetitions` is zero or `radix` isn't from 2 to 36.
+    pub fn next_radix(val: u128, repetitions: u32, radix: u32) -> Option<Self> {
+        if val == 0 {
+            return Some(Self::zero_radix(repetitions, radix));
+        }
+
+        let digits = digits(val, radix);
+        let block_digits = digits.div_ceil(repetitions);
+        if !digits.is_multiple_of(repetitions) {
+            // Nothing with this many digits can be split into blocks, so start on the first number
+            // with enough digits that can
+            let base = (radix as u128).pow(block_digits - 1);
+            return Self::from_base(base, repetitions, radix);
+        }
+
+        // The first block of `val` written out again is either the answer or just below it
+        let base = val / (radix as u128).pow(digits - block_digits);
+        let a = Self::from_base(base, repetitions, radix)?;
+        if val <= a.value() {
+            Some(a)
+        } else {
+            a.successor()
+        }
+    }
+}
+
+/// Every number with a given number of repetitions, in order, starting from a given one
+///
+/// Despite the name, this stops after the biggest one that fits in a `u128`.
+pub struct RepeatedNumberInfiniteIterator {
+    next: Option<RepeatedNumber>,
+}
+
+impl RepeatedNumberInfiniteIterator {
+    /// Iterate starting with (and including) `number`
+    pub fn starting_with(number: RepeatedNumber) -> Self {
+        Self { next: Some(number) }
+    }
+}
+
+impl Iterator for RepeatedNumberInfiniteIterator {
+    type Item = RepeatedNumber;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let n = self.next?;
+        self.next = n.successor();
+        Some(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SillyNumber;
+
+    /// Every number made of a block written `repetitions` times, up to the first one past `limit`
+    fn by_strings(repetitions: u32, limit: u128) -> Vec<u128> {
+        let mut numbers = vec![0];
+        for base in 1.. {
+            let number = base
+                .to_string()
+                .repeat(repetitions as usize)
+                .parse()
+                .unwrap();
+            numbers.push(number);
+            if number > limit {
+                return numbers;
+            }
+        }
+        unreachable!()
+    }
+
+    #[test]
+    fn test_value() {
+        assert_eq!(RepeatedNumber::from_base(0, 3, 10).unwrap().value(), 0);
+        assert_eq!(RepeatedNumber::from_base(1, 2, 10).unwrap().value(), 11);
+        assert_eq!(RepeatedNumber::from_base(1, 5, 10).unwrap().value(), 11111);
+        assert_eq!(
+            RepeatedNumber::from_base(12, 3, 10).unwrap().value(),
+            121212
+        );
+        assert_eq!(
+            RepeatedNumber::from_base(100, 2, 10).unwrap().value(),
+            100100
+        );
+        assert_eq!(
+            RepeatedNumber::from_base(824, 3, 10).unwrap().value(),
+            824824824
+        );
+        assert_eq!(
+            RepeatedNumber::from_base(58349105, 2, 10).unwrap().value(),
+            5834910558349105
+        );
+        assert_eq!(RepeatedNumber::from_base(7, 1, 10).unwrap().value(), 7);
+    }
+
+    #[test]
+    fn test_next() {
+        assert_eq!(RepeatedNumber::next(0, 3).unwrap().value(), 0);
+        assert_eq!(RepeatedNumber::next(1, 3).unwrap().value(), 111);
+        assert_eq!(RepeatedNumber::next(111, 3).unwrap().value(), 111);
+        assert_eq!(RepeatedNumber::next(112, 3).unwrap().value(), 222);
+        assert_eq!(RepeatedNumber::next(999, 3).unwrap().value(), 999);
+        assert_eq!(RepeatedNumber::next(1000, 3).unwrap().value(), 101010);
+        assert_eq!(RepeatedNumber::next(565656, 3).unwrap().value(), 565656);
+        assert_eq!(RepeatedNumber::next(565657, 3).unwrap().value(), 575757);
+        assert_eq!(
+            RepeatedNumber::next(2121212118, 5).unwrap().value(),
+            2121212121
+        );
+        assert_eq!(
+            RepeatedNumber::next(2121212122, 5).unwrap().value(),
+            2222222222
+        );
+    }
+
+    #[test]
+    fn test_next_exhaustive() {
+        for repetitions in 1..=7 {
+            let numbers = by_strings(repetitions, 200_000);
+            for v in 0..200_000 {
+                let expected = numbers[numbers.partition_point(|&n| n < v)];
+                let next = RepeatedNumber::next(v, repetitions).unwrap();
+                assert_eq!(next.value(), expected, "{v} {repetitions}");
+                assert_eq!(next.repetitions(), repetitions);
+            }
+        }
+    }
+
+    #[test]
+    fn test_matches_silly_number() {
+        for v in (0..1_000_000).step_by(7) {
+            assert_eq!(
+                RepeatedNumber::next(v, 2).unwrap().value(),
+                SillyNumber::next(v).unwrap().value()
+            );
+        }
+    }
+
+    #[test]
+    fn test_is_primitive() {
+        assert!(RepeatedNumber::next(111111, 6).unwrap().is_primitive());
+        assert!(!RepeatedNumber::next(111111, 3).unwrap().is_primitive());
+        assert!(!RepeatedNumber::next(111111, 2).unwrap().is_primitive());
+        assert!(RepeatedNumber::next(123123, 2).unwrap().is_primitive());
+        assert!(!RepeatedNumber::next(12121212, 2).unwrap().is_primitive());
+        assert!(RepeatedNumber::next(10101010, 4).unwrap().is_primitive());
+        assert!(!RepeatedNumber::zero(2).is_primitive());
+    }
+
+    #[test]
+    fn test_radix() {
+        assert_eq!(
+            RepeatedNumber::from_base(0b10, 3, 2).unwrap().value(),
+            0b101010
+        );
+        assert_eq!(
+            RepeatedNumber::from_base(0xf, 4, 16).unwrap().value(),
+            0xffff
+        );
+        assert_eq!(
+            RepeatedNumber::next_radix(0x1000, 3, 16).unwrap().value(),
+            0x101010
+        );
+        assert_eq!(
+            RepeatedNumber::next_radix(0x565657, 3, 16).unwrap().value(),
+            0x575757
+        );
+        assert!(
+            RepeatedNumber::next_radix(0b1111, 4, 2)
+                .unwrap()
+                .is_primitive()
+        );
+        assert!(
+            !RepeatedNumber::next_radix(0b1111, 2, 2)
+                .unwrap()
+                .is_primitive()
+        );
+
+        for radix in [2, 7, 16, 36] {
+            for repetitions in 2..=4 {
+                let mut expected = 0;
+                for v in 0..20_000 {
+                    if v > expected {
+                        expected = (v..)
+                            .find(|&n| {
+                                let digits = crate::to_string_radix(n, radix);
+                                let reps = repetitions as usize;
+                                digits.len().is_multiple_of(reps)
+                                    && digits[..digits.len() / reps].repeat(reps) == digits
+                            })
+                            .unwrap();
+                    }
+                    let next = RepeatedNumber::next_radix(v, repetitions, radix).unwrap();
+                    assert_eq!(next.value(), expected, "{v} {repetitions} {radix}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_limit() {
+        // Thirteen 3 digit blocks make 39 digits, which fit as long as the block is below 340
+        let biggest = RepeatedNumber::next(u128::MAX, 13);
+        assert_eq!(biggest, None);
+        let biggest = RepeatedNumber::next(339 * 10_u128.pow(36), 13).unwrap();
+        assert_eq!(biggest.base(), 339);
+        assert_eq!(biggest.successor(), None);
+        let numbers = RepeatedNumberInfiniteIterator::starting_with(biggest).count();
+        assert_eq!(numbers, 1);
+
+        assert_eq!(
+            RepeatedNumber::next_radix(u128::MAX, 128, 2)
+                .unwrap()
+                .base(),
+            1
+        );
+        assert_eq!(RepeatedNumber::next_radix(u128::MAX, 3, 2), None);
+
+        // Every number is one block written once, right up to the largest
+        let biggest = RepeatedNumber::next(u128::MAX, 1).unwrap();
+        assert_eq!(biggest.value(), u128::MAX);
+        assert_eq!(biggest.successor(), None);
+    }
+
+    #[test]
+    fn test_iterator() {
+        let numbers =
+            RepeatedNumberInfiniteIterator::starting_with(RepeatedNumber::next(900, 3).unwrap())
+                .take(4)
+                .map(|n| n.value())
+                .collect::<Vec<_>>();
+        assert_eq!(numbers, [999, 101010, 111111, 121212]);
+    }
+}
@@ -1,108 +1,245 @@
+use crate::RepeatedNumber;
+
 /// A number that repeats itself
 ///
 /// This struct can *only* represent silly numbers; it is impossible to construct one of these in
-/// such a way that it isn't a silly number.
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
-pub struct SillyNumber(usize);
+/// such a way that it isn't a silly number, or whose value doesn't fit in a `u128`. It is a
+/// [`RepeatedNumber`] whose block is always written twice.
+///
+/// Silly numbers are decimal unless they're made with one of the `_radix` functions, which take a
+/// radix from 2 to 36 and panic if it's outside that.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub struct SillyNumber(RepeatedNumber);
 
 impl SillyNumber {
-    pub const ZERO: SillyNumber = SillyNumber(0);
+    /// The silly number with the smallest value
+    pub const ZERO: SillyNumber = SillyNumber(RepeatedNumber::zero(2));
+
+    /// The silly number with the smallest value, written in `radix`
+    pub fn zero_radix(radix: u32) -> Self {
+        SillyNumber(RepeatedNumber::zero_radix(2, radix))
+    }
+
+    /// The silly number made from `base`, if its value fits
+    #[cfg(test)]
+    fn from_base(base: u128, radix: u32) -> Option<Self> {
+        RepeatedNumber::from_base(base, 2, radix).map(SillyNumber)
+    }
 
-    fn from_base(base: usize) -> Self {
-        SillyNumber(base)
+    /// The radix the number is written in
+    pub fn radix(self) -> u32 {
+        self.0.radix()
     }
 
     /// Get the value of the number
-    pub fn value(self) -> usize {
-        let val = self.0;
-        if val == 0 {
-            return 0;
-        }
+    pub fn value(self) -> u128 {
+        self.0.value()
+    }
 
-        let number_of_digits = val.ilog10() + 1;
-        let factor_of_ten = 10_usize.pow(number_of_digits);
-        let duplicated = factor_of_ten * val;
+    /// Get the silly number that follows this silly number, or `None` if its value is too big for
+    /// a `u128`
+    pub fn successor(self) -> Option<Self> {
+        self.0.successor().map(SillyNumber)
+    }
 
-        duplicated + val
+    /// Get the next silly number that has a value equal to or greater than the provided value, or
+    /// `None` if its value is too big for a `u128`
+    pub fn next(val: u128) -> Option<Self> {
+        Self::next_radix(val, 10)
     }
 
-    /// Get the silly number that follows this silly number
-    pub fn successor(self) -> Self {
-        Self(self.0 + 1)
+    /// Get the next silly number written in `radix` that has a value equal to or greater than the
+    /// provided value, or `None` if its value is too big for a `u128`
+    pub fn next_radix(val: u128, radix: u32) -> Option<Self> {
+        RepeatedNumber::next_radix(val, 2, radix).map(SillyNumber)
     }
+}
 
-    /// Get the next silly number that has a value equal to or greater than the provided value
-    pub fn next(val: usize) -> Self {
-        if val == 0 {
-            return Self(0);
-        }
+/// Every silly number, in order, starting from a given one
+///
+/// Despite the name, this stops after the biggest silly number that fits in a `u128`.
+pub struct SillyNumberInfiniteIterator {
+    next: Option<SillyNumber>,
+}
 
-        let digits = val.ilog10() + 1;
-        let half_digits = digits / 2;
-        if digits % 2 == 1 {
-            let base = 10_usize.pow(half_digits);
-            return Self::from_base(base);
+impl SillyNumberInfiniteIterator {
+    /// Iterate starting with (and including) `silly_number`
+    pub fn starting_with(silly_number: SillyNumber) -> Self {
+        Self {
+            next: Some(silly_number),
         }
+    }
+}
+
+impl Iterator for SillyNumberInfiniteIterator {
+    type Item = SillyNumber;
 
-        let base = 10_usize.pow(half_digits);
-        let something = val / base;
-        let a = SillyNumber::from_base(something);
-        if val <= a.value() { a } else { a.successor() }
+    fn next(&mut self) -> Option<Self::Item> {
+        let n = self.next?;
+        self.next = n.successor();
+        Some(n)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use common::Rng;
+
+    fn is_silly(n: u128) -> bool {
+        let digits = n.to_string();
+        let (front, back) = digits.split_at(digits.len() / 2);
+        front == back
+    }
 
     #[test]
     fn silly_number_from_base_value() {
-        assert_eq!(SillyNumber::from_base(0).value(), 0);
-        assert_eq!(SillyNumber::from_base(1).value(), 11);
-        assert_eq!(SillyNumber::from_base(2).value(), 22);
-        assert_eq!(SillyNumber::from_base(9).value(), 99);
-        assert_eq!(SillyNumber::from_base(10).value(), 1010);
-        assert_eq!(SillyNumber::from_base(11).value(), 1111);
-        assert_eq!(SillyNumber::from_base(99).value(), 9999);
-        assert_eq!(SillyNumber::from_base(100).value(), 100100);
-        assert_eq!(SillyNumber::from_base(1000).value(), 10001000);
-        assert_eq!(SillyNumber::from_base(58349105).value(), 5834910558349105);
+        assert_eq!(SillyNumber::from_base(0, 10).unwrap().value(), 0);
+        assert_eq!(SillyNumber::from_base(1, 10).unwrap().value(), 11);
+        assert_eq!(SillyNumber::from_base(2, 10).unwrap().value(), 22);
+        assert_eq!(SillyNumber::from_base(9, 10).unwrap().value(), 99);
+        assert_eq!(SillyNumber::from_base(10, 10).unwrap().value(), 1010);
+        assert_eq!(SillyNumber::from_base(11, 10).unwrap().value(), 1111);
+        assert_eq!(SillyNumber::from_base(99, 10).unwrap().value(), 9999);
+        assert_eq!(SillyNumber::from_base(100, 10).unwrap().value(), 100100);
+        assert_eq!(SillyNumber::from_base(1000, 10).unwrap().value(), 10001000);
+        assert_eq!(
+            SillyNumber::from_base(58349105, 10).unwrap().value(),
+            5834910558349105
+        );
     }
 
     #[test]
     fn silly_number_succ() {
-        let mut a = SillyNumber::from_base(0);
+        let mut a = SillyNumber::from_base(0, 10).unwrap();
         assert_eq!(a.value(), 0);
-        a = a.successor();
+        a = a.successor().unwrap();
         assert_eq!(a.value(), 11);
-        a = a.successor();
+        a = a.successor().unwrap();
         assert_eq!(a.value(), 22);
-        a = a.successor();
+        a = a.successor().unwrap();
         assert_eq!(a.value(), 33);
     }
 
     #[test]
     fn silly_number_next() {
-        assert_eq!(SillyNumber::next(0).value(), 0);
-        assert_eq!(SillyNumber::next(1).value(), 11);
-        assert_eq!(SillyNumber::next(2).value(), 11);
-        assert_eq!(SillyNumber::next(10).value(), 11);
-        assert_eq!(SillyNumber::next(11).value(), 11);
-        assert_eq!(SillyNumber::next(12).value(), 22);
-        assert_eq!(SillyNumber::next(98).value(), 99);
-        assert_eq!(SillyNumber::next(99).value(), 99);
-        assert_eq!(SillyNumber::next(100).value(), 1010);
-        assert_eq!(SillyNumber::next(999).value(), 1010);
-        assert_eq!(SillyNumber::next(1000).value(), 1010);
-        assert_eq!(SillyNumber::next(1010).value(), 1010);
-        assert_eq!(SillyNumber::next(1011).value(), 1111);
-        assert_eq!(SillyNumber::next(5000).value(), 5050);
-        assert_eq!(SillyNumber::next(9998).value(), 9999);
-        assert_eq!(SillyNumber::next(9999).value(), 9999);
-        assert_eq!(SillyNumber::next(50000).value(), 100100);
-        assert_eq!(SillyNumber::next(500000).value(), 500500);
-        assert_eq!(SillyNumber::next(500501).value(), 501501);
-        assert_eq!(SillyNumber::next(35139581).value(), 35143514);
-        assert_eq!(SillyNumber::next(351395814).value(), 1000010000);
+        assert_eq!(SillyNumber::next(0).unwrap().value(), 0);
+        assert_eq!(SillyNumber::next(1).unwrap().value(), 11);
+        assert_eq!(SillyNumber::next(2).unwrap().value(), 11);
+        assert_eq!(SillyNumber::next(10).unwrap().value(), 11);
+        assert_eq!(SillyNumber::next(11).unwrap().value(), 11);
+        assert_eq!(SillyNumber::next(12).unwrap().value(), 22);
+        assert_eq!(SillyNumber::next(98).unwrap().value(), 99);
+        assert_eq!(SillyNumber::next(99).unwrap().value(), 99);
+        assert_eq!(SillyNumber::next(100).unwrap().value(), 1010);
+        assert_eq!(SillyNumber::next(999).unwrap().value(), 1010);
+        assert_eq!(SillyNumber::next(1000).unwrap().value(), 1010);
+        assert_eq!(SillyNumber::next(1010).unwrap().value(), 1010);
+        assert_eq!(SillyNumber::next(1011).unwrap().value(), 1111);
+        assert_eq!(SillyNumber::next(5000).unwrap().value(), 5050);
+        assert_eq!(SillyNumber::next(9998).unwrap().value(), 9999);
+        assert_eq!(SillyNumber::next(9999).unwrap().value(), 9999);
+        assert_eq!(SillyNumber::next(50000).unwrap().value(), 100100);
+        assert_eq!(SillyNumber::next(500000).unwrap().value(), 500500);
+        assert_eq!(SillyNumber::next(500501).unwrap().value(), 501501);
+        assert_eq!(SillyNumber::next(35139581).unwrap().value(), 35143514);
+        assert_eq!(SillyNumber::next(351395814).unwrap().value(), 1000010000);
+    }
+
+    #[test]
+    fn silly_number_next_properties() {
+        let mut rng = Rng::new(2);
+        for _ in 0..10_000 {
+            // Pick the number of digits first so that every length is tested equally
+            let digits = rng.range(1, 18) as u32;
+            let v = rng.range(10_u64.pow(digits - 1), 10_u64.pow(digits) - 1) as u128;
+
+            let next = SillyNumber::next(v).unwrap();
+            assert!(next.value() >= v, "{v}");
+            assert!(is_silly(next.value()), "{v}");
+
+            // The silly numbers are in the same order as their bases, so checking the one before
+            // is enough to show that none were skipped
+            let previous = SillyNumber::from_base(next.0.base() - 1, 10).unwrap();
+            assert!(previous.value() < v, "{v}");
+        }
+    }
+
+    #[test]
+    fn silly_number_next_exhaustive() {
+        let mut expected = 0;
+        for v in 0..1_000_000 {
+            if v > expected {
+                expected = (v..).find(|&n| is_silly(n)).unwrap();
+            }
+            assert_eq!(SillyNumber::next(v).unwrap().value(), expected, "{v}");
+        }
+    }
+
+    #[test]
+    fn silly_number_limit() {
+        let nines = 10_u128.pow(19) - 1;
+        let biggest = SillyNumber::next(nines * 10_u128.pow(19)).unwrap();
+        assert_eq!(biggest.value(), nines * 10_u128.pow(19) + nines);
+        assert_eq!(biggest.successor(), None);
+        assert_eq!(SillyNumber::next(biggest.value() + 1), None);
+        assert_eq!(SillyNumber::next(u128::MAX), None);
+
+        let biggest = SillyNumber::next_radix(u128::MAX, 2).unwrap();
+        assert_eq!(biggest.value(), u128::MAX);
+        assert_eq!(biggest.successor(), None);
+
+        let last_two = SillyNumber::from_base(biggest.0.base() - 1, 2).unwrap();
+        let last_two = SillyNumberInfiniteIterator::starting_with(last_two);
+        assert_eq!(last_two.last(), Some(biggest));
+    }
+
+    #[test]
+    fn silly_number_radix() {
+        assert_eq!(SillyNumber::from_base(0b101, 2).unwrap().value(), 0b101101);
+        assert_eq!(SillyNumber::from_base(0xab, 16).unwrap().value(), 0xabab);
+        assert_eq!(SillyNumber::next_radix(0b100, 2).unwrap().value(), 0b1010);
+        assert_eq!(SillyNumber::next_radix(0xabac, 16).unwrap().value(), 0xacac);
+        assert_eq!(SillyNumber::next_radix(0xfff, 16).unwrap().value(), 0x1010);
+        assert_eq!(SillyNumber::next_radix(0xfff, 16).unwrap().radix(), 16);
+
+        for radix in [2, 3, 16, 36] {
+            let mut expected = 0;
+            for v in 0..100_000 {
+                if v > expected {
+                    expected = (v..)
+                        .find(|&n| {
+                            let digits = crate::to_string_radix(n, radix);
+                            let (front, back) = digits.split_at(digits.len() / 2);
+                            front == back
+                        })
+                        .unwrap();
+                }
+                assert_eq!(
+                    SillyNumber::next_radix(v, radix).unwrap().value(),
+                    expected,
+                    "{v}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn silly_number_radix_too_big() {
+        SillyNumber::next_radix(100, 37);
+    }
+
+    #[test]
+    fn silly_number_iterator() {
+        let silly_number_iterator = SillyNumberInfiniteIterator::starting_with(SillyNumber::ZERO);
+        let collected = silly_number_iterator
+            .take(12)
+            .map(|val| val.value())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            collected,
+            [0, 11, 22, 33, 44, 55, 66, 77, 88, 99, 1010, 1111]
+        );
     }
 }
@@ -1,26 +1,25 @@
-pub fn is_silly_number_part_two(num: usize) -> bool {
+use crate::{RepeatedNumber, digits};
+
+/// Whether the digits of `num` are some block of digits repeated two or more times
+pub fn is_silly_number_part_two(num: u128) -> bool {
+    is_silly_number_part_two_radix(num, 10)
+}
+
+/// Whether the digits of `num` written in `radix` are some block of digits repeated two or more
+/// times
+///
+/// Panics if `radix` isn't from 2 to 36.
+pub fn is_silly_number_part_two_radix(num: u128, radix: u32) -> bool {
     if num == 0 {
         return true;
     }
-    let total_digits = num.ilog10() + 1;
-    for digits in 1..total_digits {
-        if total_digits % digits > 0 {
-            continue;
-        }
-        let factor = 10_usize.pow(digits);
-        let part = num % factor;
-        let mut new_num = num;
-        loop {
-            if new_num == 0 {
-                return true;
-            }
-            if new_num % factor != part {
-                break;
-            }
-            new_num = (new_num - part) / factor;
-        }
-    }
-    false
+    let total_digits = digits(num, radix);
+    (2..=total_digits)
+        .filter(|&repetitions| total_digits.is_multiple_of(repetitions))
+        .any(|repetitions| {
+            RepeatedNumber::next_radix(num, repetitions, radix)
+                .is_some_and(|number| number.value() == num)
+        })
 }
 
 #[cfg(test)]
@@ -40,5 +39,12 @@ mod tests {
         assert!(is_silly_number_part_two(1111111));
 
         assert!(!is_silly_number_part_two(100));
+
+        assert!(is_silly_number_part_two_radix(0b101101, 2));
+        assert!(is_silly_number_part_two_radix(0xababab, 16));
+        assert!(!is_silly_number_part_two_radix(0xababab, 10));
+        assert!(!is_silly_number_part_two_radix(0b100, 2));
+        assert!(is_silly_number_part_two_radix(u128::MAX, 2));
+        assert!(!is_silly_number_part_two(u128::MAX));
     }
 }
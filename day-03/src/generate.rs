@@ -0,0 +1,67 @@
+use common::Rng;
+
+/// The shape of a generated list of banks
+#[derive(Clone, Debug)]
+pub struct GeneratorConfig {
+    /// How many banks to write
+    pub banks: usize,
+    /// How many batteries are in each bank, at least 12 so that part two can be solved
+    pub bank_len: usize,
+}
+
+impl GeneratorConfig {
+    /// A config for `size` banks
+    pub fn with_size(size: usize) -> Self {
+        Self {
+            banks: size,
+            bank_len: 100,
+        }
+    }
+}
+
+/// Write one bank of batteries per line, each battery a joltage from 1 to 9
+///
+/// Banks shorter than 12 batteries are written 12 long instead, since part two turns on 12 of them.
+pub fn generate(seed: u64, config: &GeneratorConfig) -> String {
+    let mut rng = Rng::new(seed);
+    let mut out = String::new();
+    for _ in 0..config.banks {
+        for _ in 0..config.bank_len.max(12) {
+            out.push((b'0' + rng.range(1, 9) as u8) as char);
+        }
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate() {
+        let config = GeneratorConfig {
+            banks: 20,
+            bank_len: 30,
+        };
+        let input = generate(3, &config);
+        let banks = crate::parse(&input).unwrap();
+        assert_eq!(banks.len(), 20);
+        for bank in banks {
+            assert_eq!(bank.len(), 30);
+            assert!(!bank.contains('0'));
+        }
+    }
+
+    #[test]
+    fn test_generate_short_banks() {
+        let config = GeneratorConfig {
+            banks: 5,
+            bank_len: 3,
+        };
+        let input = generate(3, &config);
+        let banks = crate::parse(&input).unwrap();
+        assert!(banks.iter().all(|bank| bank.len() == 12));
+        crate::solve(&input, 12).unwrap();
+    }
+}
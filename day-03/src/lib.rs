@@ -0,0 +1,187 @@
+//! Day 3: picking the batteries with the most joltage
+
+use common::{Answer, ParseError, Parsed, Part, Solver};
+
+pub use generate::{GeneratorConfig, generate};
+
+mod generate;
+pub mod reference;
+
+/// The puzzle input
+pub const INPUT: &str = include_str!("input.txt");
+
+/// The [`Solver`] for day 3
+pub struct Day03;
+
+impl Solver for Day03 {
+    fn day(&self) -> u8 {
+        3
+    }
+
+    fn input(&self) -> &'static str {
+        INPUT
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Box<dyn Parsed + 'a>, ParseError> {
+        let banks = parse(input)?;
+        Ok(Box::new(Banks { text: input, banks }))
+    }
+
+    fn part_one(&self, input: &str) -> Result<Answer, ParseError> {
+        solve(input, 2).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, ParseError> {
+        solve(input, 12).map(Answer::from)
+    }
+
+    fn generate(&self, seed: u64, size: usize) -> String {
+        generate(seed, &GeneratorConfig::with_size(size))
+    }
+
+    fn reference(&self, part: Part, input: &str) -> Result<Answer, ParseError> {
+        match part {
+            Part::One => reference::solve(input, 2).map(Answer::from),
+            Part::Two => reference::solve(input, 12).map(Answer::from),
+        }
+    }
+}
+
+/// Sum the maximum joltage of every bank, turning on `count` batteries in each
+pub fn solve(s: &str, count: usize) -> Result<usize, ParseError> {
+    let banks = parse(s)?;
+    Banks { text: s, banks }.total(count)
+}
+
+/// The parsed banks, with the text they were parsed from so that errors can point back at it
+struct Banks<'a> {
+    text: &'a str,
+    banks: Vec<&'a str>,
+}
+
+impl Banks<'_> {
+    /// Sum the maximum joltage of every bank, turning on `count` batteries in each
+    fn total(&self, count: usize) -> Result<usize, ParseError> {
+        let mut sum: usize = 0;
+        for &bank in &self.banks {
+            let v = max_joltage(bank, count).map_err(|err| err.within(self.text, bank))?;
+            sum = sum.checked_add(v).ok_or_else(|| {
+                ParseError::new(bank, "joltage is too big").within(self.text, bank)
+            })?;
+        }
+        Ok(sum)
+    }
+}
+
+impl Parsed for Banks<'_> {
+    fn part_one(&self) -> Result<Answer, ParseError> {
+        self.total(2).map(Answer::from)
+    }
+
+    fn part_two(&self) -> Result<Answer, ParseError> {
+        self.total(12).map(Answer::from)
+    }
+}
+
+/// Parse one bank of batteries per line
+pub fn parse(s: &str) -> Result<Vec<&str>, ParseError> {
+    s.trim()
+        .lines()
+        .map(|line| {
+            let line = line.trim();
+            check_digits(line).map_err(|err| err.within(s, line))?;
+            Ok(line)
+        })
+        .collect()
+}
+
+fn check_digits(bank: &str) -> Result<(), ParseError> {
+    match bank.chars().enumerate().find(|(_, c)| !c.is_ascii_digit()) {
+        Some((column, c)) => Err(ParseError::new(c, "expected a digit").at(1, column + 1)),
+        None => Ok(()),
+    }
+}
+
+/// The largest number that can be made by picking `count` digits from the bank, in order
+///
+/// Fails if `s` contains anything other than ASCII digits, has fewer than `count` of them, or the
+/// number is too big for a `usize`.
+pub fn max_joltage(s: &str, count: usize) -> Result<usize, ParseError> {
+    fn max_rec(s: &str, count: usize, accum: usize) -> Option<usize> {
+        if count == 0 {
+            return Some(accum);
+        }
+
+        let bytes = s.as_bytes();
+        let len = s.len();
+        let mut max_value = 0;
+        let mut max_index = 0;
+        for (i, &byte) in bytes[..=(len - count)].iter().enumerate() {
+            if byte > max_value {
+                max_value = byte;
+                max_index = i;
+            }
+        }
+        let val = (max_value - b'0') as usize;
+        let accum = accum.checked_mul(10)?.checked_add(val)?;
+
+        max_rec(&s[max_index + 1..], count - 1, accum)
+    }
+
+    check_digits(s)?;
+    if s.len() < count {
+        return Err(ParseError::new(
+            s,
+            format!("expected a bank of at least {count} batteries"),
+        ));
+    }
+
+    max_rec(s, count, 0).ok_or_else(|| ParseError::new(s, "joltage is too big"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_max_joltage() {
+        assert_eq!(max_joltage("987654321111111", 2).unwrap(), 98);
+        assert_eq!(max_joltage("811111111111119", 2).unwrap(), 89);
+        assert_eq!(max_joltage("234234234234278", 2).unwrap(), 78);
+        assert_eq!(max_joltage("818181911112111", 2).unwrap(), 92);
+
+        let bank = "9".repeat(25);
+        assert_eq!(max_joltage(&bank, 19).unwrap(), 10_usize.pow(19) - 1);
+        let err = max_joltage(&bank, 20).unwrap_err();
+        assert_eq!(err.reason(), "joltage is too big");
+    }
+
+    #[test]
+    fn test_part_one() {
+        let input = include_str!("example.txt");
+        assert_eq!(solve(input, 2).unwrap(), 357);
+    }
+
+    #[test]
+    fn test_max_joltage_twelve() {
+        assert_eq!(max_joltage("987654321111111", 12).unwrap(), 987654321111);
+        assert_eq!(max_joltage("811111111111119", 12).unwrap(), 811111111119);
+        assert_eq!(max_joltage("234234234234278", 12).unwrap(), 434234234278);
+        assert_eq!(max_joltage("818181911112111", 12).unwrap(), 888911112111);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = include_str!("example.txt");
+        assert_eq!(solve(input, 12).unwrap(), 3121910778619);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = solve("987654321111111\n81111x111111119\n", 2).unwrap_err();
+        assert_eq!((err.line(), err.column()), (2, 6));
+
+        let err = solve("987654321111111\n8111\n", 12).unwrap_err();
+        assert_eq!((err.line(), err.column(), err.text()), (2, 1, "8111"));
+    }
+}
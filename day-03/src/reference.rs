@@ -0,0 +1,95 @@
+//! A slower way to solve the puzzle that doesn't rely on picking greedily
+//!
+//! For every suffix of a bank and every count, it works out the best joltage from either picking or
+//! skipping the first battery of the suffix. That is O(n·k) per bank instead of trying every
+//! combination, which would be too slow for the generated banks, so the tests check it against
+//! every combination on short banks instead.
+
+use crate::parse;
+use common::ParseError;
+
+/// The largest joltage from picking `count` batteries from `bank`, if it has enough
+fn max_joltage(bank: &[u8], count: usize) -> Option<usize> {
+    // best[i][k] is the largest joltage from picking k batteries out of bank[i..]
+    let mut best = vec![vec![None; count + 1]; bank.len() + 1];
+    for row in &mut best {
+        row[0] = Some(0);
+    }
+    for i in (0..bank.len()).rev() {
+        for k in 1..=count {
+            let skip = best[i + 1][k];
+            let digit = (bank[i] - b'0') as usize;
+            let pick = best[i + 1][k - 1].map(|rest| digit * 10_usize.pow(k as u32 - 1) + rest);
+            best[i][k] = skip.max(pick);
+        }
+    }
+    best[0][count]
+}
+
+/// Sum the maximum joltage of every bank, turning on `count` batteries in each
+pub fn solve(s: &str, count: usize) -> Result<usize, ParseError> {
+    let mut sum = 0;
+    for bank in parse(s)? {
+        let joltage = max_joltage(bank.as_bytes(), count).ok_or_else(|| {
+            ParseError::new(
+                bank,
+                format!("expected a bank of at least {count} batteries"),
+            )
+            .within(s, bank)
+        })?;
+        sum += joltage;
+    }
+    Ok(sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day03;
+    use common::{Part, Rng, differential};
+
+    #[test]
+    fn test_example() {
+        let input = include_str!("example.txt");
+        assert_eq!(solve(input, 2), Ok(357));
+        assert_eq!(solve(input, 12), Ok(3121910778619));
+    }
+
+    /// The largest joltage out of every combination of `count` batteries
+    fn by_combinations(bank: &[u8], count: usize) -> Option<usize> {
+        (0_u32..1 << bank.len())
+            .filter(|picked| picked.count_ones() as usize == count)
+            .map(|picked| {
+                (0..bank.len())
+                    .filter(|i| picked & 1 << i != 0)
+                    .fold(0, |joltage, i| joltage * 10 + (bank[i] - b'0') as usize)
+            })
+            .max()
+    }
+
+    #[test]
+    fn test_combinations() {
+        let mut rng = Rng::new(9);
+        for _ in 0..300 {
+            let len = rng.range(0, 14) as usize;
+            let bank = (0..len)
+                .map(|_| b'0' + rng.range(1, 9) as u8)
+                .collect::<Vec<_>>();
+            for count in 0..=len + 1 {
+                assert_eq!(
+                    max_joltage(&bank, count),
+                    by_combinations(&bank, count),
+                    "{bank:?} {count}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_differential() {
+        for part in Part::ALL {
+            let disagreement = differential::check(&Day03, part, 0..50, 20);
+            assert!(disagreement.is_none(), "{disagreement:?}");
+        }
+    }
+}
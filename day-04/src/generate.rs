@@ -0,0 +1,64 @@
+use common::Rng;
+
+/// The shape of a generated grid
+#[derive(Clone, Debug)]
+pub struct GeneratorConfig {
+    pub width: usize,
+    pub height: usize,
+    /// The percentage of positions that hold a roll of paper
+    pub density: u8,
+}
+
+impl GeneratorConfig {
+    /// A config for a square grid `size` positions on each side
+    pub fn with_size(size: usize) -> Self {
+        Self {
+            width: size,
+            height: size,
+            density: 65,
+        }
+    }
+}
+
+/// Write a grid of `.` and `@`
+pub fn generate(seed: u64, config: &GeneratorConfig) -> String {
+    let mut rng = Rng::new(seed);
+    let mut out = String::new();
+    for _ in 0..config.height {
+        for _ in 0..config.width {
+            out.push(if rng.chance(config.density) { '@' } else { '.' });
+        }
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Entry, Grid};
+
+    fn papers(grid: &Grid) -> usize {
+        let (width, height) = (grid.width() as isize, grid.height() as isize);
+        (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .filter(|&(x, y)| grid.get(x, y) == Some(Entry::Paper))
+            .count()
+    }
+
+    #[test]
+    fn test_generate() {
+        let mut config = GeneratorConfig {
+            width: 30,
+            height: 20,
+            density: 0,
+        };
+        let grid = Grid::parse(&generate(3, &config)).unwrap();
+        assert_eq!((grid.width(), grid.height()), (30, 20));
+        assert_eq!(papers(&grid), 0);
+
+        config.density = 100;
+        let grid = Grid::parse(&generate(3, &config)).unwrap();
+        assert_eq!(papers(&grid), 30 * 20);
+    }
+}
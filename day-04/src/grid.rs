@@ -0,0 +1,141 @@
+use common::ParseError;
+
+/// A grid of empty spaces and rolls of paper
+#[derive(Clone, Debug)]
+pub struct Grid {
+    width: usize,
+    height: usize,
+    data: Vec<Vec<Entry>>,
+}
+
+impl Grid {
+    /// Parse a grid written with `.` for empty spaces and `@` for paper
+    ///
+    /// Every row must be the same width.
+    pub fn parse(str: &str) -> Result<Self, ParseError> {
+        let mut width = 0;
+        let mut data = Vec::new();
+        let lines = str.trim().lines();
+        for line in lines {
+            let line = line.trim();
+            let mut row = Vec::new();
+            for (column, c) in line.chars().enumerate() {
+                let entry = match c {
+                    '.' => Entry::Empty,
+                    '@' => Entry::Paper,
+                    _ => {
+                        return Err(ParseError::new(c, "expected . or @")
+                            .at(1, column + 1)
+                            .within(str, line));
+                    }
+                };
+                row.push(entry);
+            }
+            if !data.is_empty() && row.len() != width {
+                return Err(ParseError::new(
+                    line,
+                    format!("expected a row {width} wide like the ones before it"),
+                )
+                .within(str, line));
+            }
+            width = row.len();
+            data.push(row);
+        }
+
+        let height = data.len();
+
+        Ok(Self {
+            width,
+            height,
+            data,
+        })
+    }
+
+    /// The number of columns in the grid
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The number of rows in the grid
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Get the entry at a position, or `None` if it's outside the grid
+    pub fn get(&self, x: isize, y: isize) -> Option<Entry> {
+        if x < 0 {
+            return None;
+        }
+        if y < 0 {
+            return None;
+        }
+        let x = x as usize;
+        let y = y as usize;
+
+        if y >= self.height {
+            return None;
+        }
+        if x >= self.width {
+            return None;
+        }
+        Some(self.data[y][x])
+    }
+
+    /// Clear the entry at a position; positions outside the grid are ignored
+    pub fn remove(&mut self, x: isize, y: isize) {
+        if x < 0 {
+            return;
+        }
+        if y < 0 {
+            return;
+        }
+        let x = x as usize;
+        let y = y as usize;
+
+        if y >= self.height {
+            return;
+        }
+        if x >= self.width {
+            return;
+        }
+        self.data[y][x] = Entry::Empty;
+    }
+}
+
+/// A single position in a [`Grid`]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Entry {
+    /// Nothing there
+    Empty,
+    /// A roll of paper
+    Paper,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let mut grid = Grid::parse("..@\n@@.\n").unwrap();
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid.get(0, 0), Some(Entry::Empty));
+        assert_eq!(grid.get(2, 0), Some(Entry::Paper));
+        assert_eq!(grid.get(0, 1), Some(Entry::Paper));
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.get(0, -1), None);
+
+        grid.remove(0, 1);
+        assert_eq!(grid.get(0, 1), Some(Entry::Empty));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Grid::parse("..@\n@x.\n").unwrap_err();
+        assert_eq!((err.line(), err.column(), err.text()), (2, 2, "x"));
+
+        let err = Grid::parse("..@\n@@\n").unwrap_err();
+        assert_eq!((err.line(), err.column(), err.text()), (2, 1, "@@"));
+    }
+}
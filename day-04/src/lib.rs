@@ -0,0 +1,159 @@
+//! Day 4: finding rolls of paper that a forklift can reach
+
+use common::{Answer, ParseError, Parsed, Part, Solver};
+
+pub use generate::{GeneratorConfig, generate};
+pub use grid::{Entry, Grid};
+
+mod generate;
+mod grid;
+pub mod reference;
+
+/// The puzzle input
+pub const INPUT: &str = include_str!("input.txt");
+
+/// The [`Solver`] for day 4
+pub struct Day04;
+
+impl Solver for Day04 {
+    fn day(&self) -> u8 {
+        4
+    }
+
+    fn input(&self) -> &'static str {
+        INPUT
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Box<dyn Parsed + 'a>, ParseError> {
+        Ok(Box::new(Grid::parse(input)?))
+    }
+
+    fn part_one(&self, input: &str) -> Result<Answer, ParseError> {
+        part_one(input).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, ParseError> {
+        part_two(input).map(Answer::from)
+    }
+
+    fn generate(&self, seed: u64, size: usize) -> String {
+        generate(seed, &GeneratorConfig::with_size(size))
+    }
+
+    fn reference(&self, part: Part, input: &str) -> Result<Answer, ParseError> {
+        match part {
+            Part::One => reference::part_one(input).map(Answer::from),
+            Part::Two => reference::part_two(input).map(Answer::from),
+        }
+    }
+}
+
+/// Count the rolls of paper that have fewer than four neighboring rolls
+pub fn part_one(str: &str) -> Result<usize, ParseError> {
+    Grid::parse(str).map(|grid| count_reachable(&grid))
+}
+
+/// Count the rolls of paper that can be removed by repeatedly removing every reachable roll
+pub fn part_two(str: &str) -> Result<usize, ParseError> {
+    Grid::parse(str).map(remove_reachable)
+}
+
+impl Parsed for Grid {
+    fn part_one(&self) -> Result<Answer, ParseError> {
+        Ok(Answer::from(count_reachable(self)))
+    }
+
+    fn part_two(&self) -> Result<Answer, ParseError> {
+        Ok(Answer::from(remove_reachable(self.clone())))
+    }
+}
+
+/// Count the rolls of paper in `grid` that have fewer than four neighboring rolls
+fn count_reachable(grid: &Grid) -> usize {
+    let mut count = 0;
+    for y in 0..grid.height() as isize {
+        for x in 0..grid.width() as isize {
+            let mut neighbor_count = 0;
+
+            for (nx, ny) in [
+                (x - 1, y - 1),
+                (x, y - 1),
+                (x + 1, y - 1),
+                (x - 1, y),
+                (x + 1, y),
+                (x - 1, y + 1),
+                (x, y + 1),
+                (x + 1, y + 1),
+            ] {
+                if grid.get(nx, ny) == Some(Entry::Paper) {
+                    neighbor_count += 1;
+                }
+            }
+
+            if grid.get(x, y) == Some(Entry::Paper) && neighbor_count < 4 {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+/// Remove every reachable roll of paper from `grid` until none are left, counting them
+fn remove_reachable(mut grid: Grid) -> usize {
+    let mut removed = 0;
+    loop {
+        let mut removed_this_time = 0;
+        for y in 0..grid.height() as isize {
+            for x in 0..grid.width() as isize {
+                let mut neighbor_count = 0;
+
+                for (nx, ny) in [
+                    (x - 1, y - 1),
+                    (x, y - 1),
+                    (x + 1, y - 1),
+                    (x - 1, y),
+                    (x + 1, y),
+                    (x - 1, y + 1),
+                    (x, y + 1),
+                    (x + 1, y + 1),
+                ] {
+                    if grid.get(nx, ny) == Some(Entry::Paper) {
+                        neighbor_count += 1;
+                    }
+                }
+
+                if grid.get(x, y) == Some(Entry::Paper) && neighbor_count < 4 {
+                    removed_this_time += 1;
+                    grid.remove(x, y);
+                }
+            }
+        }
+
+        if removed_this_time > 0 {
+            removed += removed_this_time;
+        } else {
+            break;
+        }
+    }
+
+    removed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = include_str!("example.txt");
+        let value = part_one(input).unwrap();
+        assert_eq!(value, 13);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = include_str!("example.txt");
+        let value = part_two(input).unwrap();
+        assert_eq!(value, 43);
+    }
+}
@@ -0,0 +1,84 @@
+//! The slow, obvious way to solve the puzzle: work on the characters directly, and remove rolls in
+//! whole rounds
+
+use common::ParseError;
+
+fn rows(input: &str) -> Result<Vec<Vec<char>>, ParseError> {
+    // Parse with the real grid first so that bad input is reported the same way
+    crate::Grid::parse(input)?;
+    Ok(input
+        .trim()
+        .lines()
+        .map(|line| line.trim().chars().collect())
+        .collect())
+}
+
+fn accessible(rows: &[Vec<char>], x: usize, y: usize) -> bool {
+    if rows[y][x] != '@' {
+        return false;
+    }
+    let mut neighbors = 0;
+    for ny in y.saturating_sub(1)..=y + 1 {
+        for nx in x.saturating_sub(1)..=x + 1 {
+            if (nx, ny) != (x, y) && rows.get(ny).and_then(|row| row.get(nx)) == Some(&'@') {
+                neighbors += 1;
+            }
+        }
+    }
+    neighbors < 4
+}
+
+fn all_accessible(rows: &[Vec<char>]) -> Vec<(usize, usize)> {
+    let mut found = Vec::new();
+    for y in 0..rows.len() {
+        for x in 0..rows[y].len() {
+            if accessible(rows, x, y) {
+                found.push((x, y));
+            }
+        }
+    }
+    found
+}
+
+/// Count the rolls of paper that have fewer than four neighboring rolls
+pub fn part_one(input: &str) -> Result<usize, ParseError> {
+    Ok(all_accessible(&rows(input)?).len())
+}
+
+/// Count the rolls of paper that can be removed by repeatedly removing every reachable roll
+pub fn part_two(input: &str) -> Result<usize, ParseError> {
+    let mut rows = rows(input)?;
+    let mut removed = 0;
+    loop {
+        let found = all_accessible(&rows);
+        if found.is_empty() {
+            return Ok(removed);
+        }
+        removed += found.len();
+        for (x, y) in found {
+            rows[y][x] = '.';
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day04;
+    use common::{Part, differential};
+
+    #[test]
+    fn test_example() {
+        let input = include_str!("example.txt");
+        assert_eq!(part_one(input), Ok(13));
+        assert_eq!(part_two(input), Ok(43));
+    }
+
+    #[test]
+    fn test_differential() {
+        for part in Part::ALL {
+            let disagreement = differential::check(&Day04, part, 0..30, 15);
+            assert!(disagreement.is_none(), "{disagreement:?}");
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use crate::Range;
+use common::Rng;
+
+/// The shape of a generated database
+#[derive(Clone, Debug)]
+pub struct GeneratorConfig {
+    /// How many fresh ranges to write
+    pub ranges: usize,
+    /// How many available ingredient IDs to write
+    pub ids: usize,
+    /// The largest ID that can appear
+    pub max_id: u64,
+    /// The most IDs a single range can cover
+    pub max_width: u64,
+}
+
+impl GeneratorConfig {
+    /// A config for `size` ranges and `size` IDs
+    pub fn with_size(size: usize) -> Self {
+        Self {
+            ranges: size,
+            ids: size,
+            max_id: 1_000_000_000_000,
+            max_width: 10_000_000_000,
+        }
+    }
+}
+
+/// Write the fresh ranges, a blank line, then the available IDs
+///
+/// Some ranges are placed right next to or on top of an earlier one, and some IDs are placed on
+/// the edge of a range, since those are where merging and lookups are most likely to go wrong.
+///
+/// At least one range is always written, since a database that starts with its blank line
+/// doesn't parse.
+pub fn generate(seed: u64, config: &GeneratorConfig) -> String {
+    let mut rng = Rng::new(seed);
+    let mut ranges: Vec<Range> = Vec::new();
+    for _ in 0..config.ranges.max(1) {
+        let width = rng.range(0, config.max_width);
+        let from = match ranges.last() {
+            Some(last) if rng.chance(20) => last.to.saturating_add(1),
+            Some(last) if rng.chance(20) => rng.range(last.from, last.to),
+            _ => rng.range(0, config.max_id),
+        };
+        let from = from.min(config.max_id);
+        let to = from.saturating_add(width).min(config.max_id);
+        ranges.push(Range::new(from, to));
+    }
+
+    let mut out = String::new();
+    for range in &ranges {
+        out.push_str(&format!("{}-{}\n", range.from, range.to));
+    }
+    out.push('\n');
+    for _ in 0..config.ids {
+        let id = if rng.chance(50) {
+            rng.range(0, config.max_id)
+        } else {
+            let range = ranges[rng.below(ranges.len())];
+            match rng.range(0, 2) {
+                0 => range.from.saturating_sub(1),
+                1 => range.from,
+                _ => range.to.saturating_add(1).min(config.max_id),
+            }
+        };
+        out.push_str(&format!("{id}\n"));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate() {
+        let config = GeneratorConfig {
+            ranges: 40,
+            ids: 25,
+            max_id: 1000,
+            max_width: 50,
+        };
+        let input = generate(3, &config);
+        let (_, ids) = crate::parse(&input).unwrap();
+        assert_eq!(ids.len(), 25);
+        assert!(ids.iter().all(|&id| id <= config.max_id));
+
+        let (ranges, _) = input.split_once("\n\n").unwrap();
+        assert_eq!(ranges.lines().count(), 40);
+        for line in ranges.lines() {
+            let range: Range = line.parse().unwrap();
+            assert!(range.to <= config.max_id);
+            assert!(range.to - range.from <= config.max_width);
+        }
+    }
+
+    #[test]
+    fn test_generate_no_ranges() {
+        let config = GeneratorConfig {
+            ranges: 0,
+            ..GeneratorConfig::with_size(10)
+        };
+        let input = generate(3, &config);
+        let (lookup, ids) = crate::parse(&input).unwrap();
+        assert_eq!(ids.len(), 10);
+        assert!(lookup.total() > 0);
+
+        let input = generate(3, &GeneratorConfig::with_size(0));
+        assert_eq!(crate::parse(&input).unwrap().1, []);
+    }
+}
@@ -0,0 +1,148 @@
+//! Day 5: checking ingredient IDs against ranges of fresh ones
+
+use common::{Answer, ParseError, Parsed, Part, Solver};
+
+pub use generate::{GeneratorConfig, generate};
+pub use lookup::Lookup;
+pub use range::Range;
+
+mod generate;
+mod lookup;
+mod range;
+pub mod reference;
+
+/// The puzzle input
+pub const INPUT: &str = include_str!("input.txt");
+
+/// The [`Solver`] for day 5
+pub struct Day05;
+
+impl Solver for Day05 {
+    fn day(&self) -> u8 {
+        5
+    }
+
+    fn input(&self) -> &'static str {
+        INPUT
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Box<dyn Parsed + 'a>, ParseError> {
+        let (lookup, ids) = parse(input)?;
+        Ok(Box::new(Inventory { lookup, ids }))
+    }
+
+    fn part_one(&self, input: &str) -> Result<Answer, ParseError> {
+        part_one(input).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, ParseError> {
+        part_two(input).map(Answer::from)
+    }
+
+    fn generate(&self, seed: u64, size: usize) -> String {
+        generate(seed, &GeneratorConfig::with_size(size))
+    }
+
+    fn reference(&self, part: Part, input: &str) -> Result<Answer, ParseError> {
+        match part {
+            Part::One => reference::part_one(input).map(Answer::from),
+            Part::Two => reference::part_two(input).map(Answer::from),
+        }
+    }
+}
+
+/// Count the available ingredient IDs that fall within any fresh range
+pub fn part_one(input: &str) -> Result<usize, ParseError> {
+    let (lookup, ids) = parse(input)?;
+    Ok(count_fresh(&lookup, &ids))
+}
+
+fn count_fresh(lookup: &Lookup, ids: &[u64]) -> usize {
+    let mut count = 0;
+    for &id in ids {
+        if lookup.contains(id) {
+            count += 1;
+        }
+    }
+    count
+}
+
+/// Count every ID that is considered fresh by at least one range
+pub fn part_two(input: &str) -> Result<u128, ParseError> {
+    let (lookup, _) = parse(input)?;
+    Ok(lookup.total())
+}
+
+/// The fresh ranges and the available ingredient IDs, once they have been parsed
+struct Inventory {
+    lookup: Lookup,
+    ids: Vec<u64>,
+}
+
+impl Parsed for Inventory {
+    fn part_one(&self) -> Result<Answer, ParseError> {
+        Ok(Answer::from(count_fresh(&self.lookup, &self.ids)))
+    }
+
+    fn part_two(&self) -> Result<Answer, ParseError> {
+        Ok(Answer::from(self.lookup.total()))
+    }
+}
+
+/// Parse the fresh ranges, then a blank line, then the available ingredient IDs
+pub fn parse(input: &str) -> Result<(Lookup, Vec<u64>), ParseError> {
+    let mut lines = input.trim().lines();
+    let mut ranges = Vec::new();
+    for line in lines.by_ref() {
+        if line.trim().is_empty() {
+            break;
+        }
+
+        let range: Range = line
+            .parse()
+            .map_err(|err: ParseError| err.within(input, line))?;
+        ranges.push(range);
+    }
+    let lookup = Lookup::from(ranges);
+
+    let mut ids = Vec::new();
+    for line in lines {
+        let id = line
+            .parse()
+            .map_err(|_| ParseError::new(line, "expected an ingredient ID").within(input, line))?;
+        ids.push(id);
+    }
+
+    Ok((lookup, ids))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = include_str!("example.txt");
+        let value = part_one(input).unwrap();
+        assert_eq!(value, 3);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = include_str!("example.txt");
+        let value = part_two(input).unwrap();
+        assert_eq!(value, 14);
+
+        let value = part_two("0-18446744073709551615\n5-6\n\n1\n").unwrap();
+        assert_eq!(value, 1 << 64);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = part_one("3-5\n10-x\n\n1\n").unwrap_err();
+        assert_eq!((err.line(), err.column(), err.text()), (2, 4, "x"));
+
+        let err = part_one("3-5\n10-14\n\n1\nfive\n").unwrap_err();
+        assert_eq!((err.line(), err.column(), err.text()), (5, 1, "five"));
+    }
+}
@@ -1,12 +1,14 @@
 use crate::range::Range;
 
+/// A set of IDs made up of ranges, merged so that membership checks are a binary search
 pub struct Lookup {
     ranges: Box<[Range]>,
 }
 
 impl Lookup {
+    /// Build a lookup from ranges that may overlap or touch
     pub fn from(ranges: impl AsRef<[Range]>) -> Self {
-        let mut ranges = ranges.as_ref().iter().copied().collect::<Vec<_>>();
+        let mut ranges = ranges.as_ref().to_vec();
         ranges.sort();
         let mut combined = Vec::new();
         let mut current: Option<Range> = None;
@@ -32,6 +34,7 @@ impl Lookup {
         }
     }
 
+    /// Whether any of the ranges contain `value`
     pub fn contains(&self, value: u64) -> bool {
         let mut low = 0;
         let mut high = self.ranges.len();
@@ -51,7 +54,8 @@ impl Lookup {
         false
     }
 
-    pub fn total(&self) -> u64 {
+    /// The number of distinct IDs covered by the ranges
+    pub fn total(&self) -> u128 {
         self.ranges.iter().map(|range| range.size()).sum()
     }
 }
@@ -59,6 +63,8 @@ impl Lookup {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use common::Rng;
+    use std::collections::BTreeSet;
 
     #[test]
     fn test_basic() {
@@ -120,5 +126,31 @@ mod tests {
 
         let lookup = Lookup::from([Range::new(1, 4), Range::new(5, 5), Range::new(6, 7)]);
         assert_eq!(lookup.total(), 7);
+
+        let lookup = Lookup::from([Range::new(0, u64::MAX), Range::new(5, 6)]);
+        assert_eq!(lookup.total(), 1 << 64);
+    }
+
+    #[test]
+    fn test_matches_set_union() {
+        let mut rng = Rng::new(3);
+        for _ in 0..1000 {
+            let ranges = (0..rng.range(0, 10))
+                .map(|_| {
+                    let from = rng.range(0, 200);
+                    Range::new(from, from + rng.range(0, 30))
+                })
+                .collect::<Vec<_>>();
+            let union = ranges
+                .iter()
+                .flat_map(|range| range.from..=range.to)
+                .collect::<BTreeSet<_>>();
+
+            let lookup = Lookup::from(&ranges);
+            assert_eq!(lookup.total(), union.len() as u128, "{ranges:?}");
+            for n in 0..=240 {
+                assert_eq!(lookup.contains(n), union.contains(&n), "{ranges:?} {n}");
+            }
+        }
     }
 }
@@ -1,28 +1,38 @@
+use common::ParseError;
 use std::str::FromStr;
 
+/// An inclusive range of IDs, written as `3-5`
 #[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq)]
 pub struct Range {
+    /// The first ID in the range
     pub from: u64,
+    /// The last ID in the range
     pub to: u64,
 }
 
 impl Range {
+    /// Create a range from `from` to `to`, inclusive
+    ///
+    /// Panics if `from` is greater than `to`.
     pub fn new(from: u64, to: u64) -> Self {
         assert!(from <= to);
 
         Range { from, to }
     }
 
+    /// Whether `n` is within the range
     pub fn contains(&self, n: u64) -> bool {
         self.from <= n && n <= self.to
     }
 
+    /// Merge two ranges into one, if they overlap or are adjacent
     pub fn combine(self, other: Self) -> Option<Self> {
         if self.from > other.from {
             return other.combine(self);
         }
 
-        if (self.to + 1) >= other.from {
+        // A range that ends on the last ID can't have a gap after it
+        if self.to.checked_add(1).is_none_or(|next| next >= other.from) {
             let to = std::cmp::max(self.to, other.to);
             Some(Self::new(self.from, to))
         } else {
@@ -30,18 +40,28 @@ impl Range {
         }
     }
 
-    pub fn size(&self) -> u64 {
-        self.to - self.from + 1
+    /// The number of IDs in the range, which is one more than a `u64` can hold for the full range
+    pub fn size(&self) -> u128 {
+        (self.to - self.from) as u128 + 1
     }
 }
 
 impl FromStr for Range {
-    type Err = &'static str;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (from, to) = s.split_once('-').ok_or("invalid range")?;
-        let from = from.parse().map_err(|_| "invalid range")?;
-        let to = to.parse().map_err(|_| "invalid range")?;
+        let (from, to) = s
+            .split_once('-')
+            .ok_or_else(|| ParseError::new(s, "expected a range like 3-5"))?;
+        let from = from
+            .parse()
+            .map_err(|_| ParseError::new(from, "expected a number"))?;
+        let to = to
+            .parse()
+            .map_err(|_| ParseError::new(to, "expected a number").within(s, to))?;
+        if from > to {
+            return Err(ParseError::new(s, "range starts after it ends"));
+        }
         Ok(Self::new(from, to))
     }
 }
@@ -49,6 +69,18 @@ impl FromStr for Range {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use common::Rng;
+
+    /// A short range, sometimes pushed up against the largest ID
+    fn random_range(rng: &mut Rng) -> Range {
+        let from = rng.range(0, 100);
+        let range = Range::new(from, from + rng.range(0, 20));
+        if rng.chance(30) {
+            Range::new(u64::MAX - range.to, u64::MAX - range.from)
+        } else {
+            range
+        }
+    }
 
     #[test]
     fn test_contains() {
@@ -89,12 +121,53 @@ mod tests {
 
         let output = Range::new(1, 3).combine(Range::new(5, 7));
         assert_eq!(output, None);
+
+        let output = Range::new(0, u64::MAX).combine(Range::new(5, 6));
+        assert_eq!(output, Some(Range::new(0, u64::MAX)));
+
+        let output = Range::new(5, u64::MAX).combine(Range::new(u64::MAX, u64::MAX));
+        assert_eq!(output, Some(Range::new(5, u64::MAX)));
+    }
+
+    #[test]
+    fn test_combine_properties() {
+        let mut rng = Rng::new(5);
+        for _ in 0..10_000 {
+            let a = random_range(&mut rng);
+            let b = random_range(&mut rng);
+            let combined = a.combine(b);
+            assert_eq!(combined, b.combine(a), "{a:?} {b:?}");
+
+            match combined {
+                Some(c) => {
+                    // The result covers both ranges and nothing outside of them
+                    assert_eq!(c.from, a.from.min(b.from), "{a:?} {b:?}");
+                    assert_eq!(c.to, a.to.max(b.to), "{a:?} {b:?}");
+                    for n in c.from..=c.to {
+                        assert!(a.contains(n) || b.contains(n), "{a:?} {b:?} {n}");
+                    }
+                    assert_eq!(c.size(), (c.from..=c.to).count() as u128);
+                }
+                None => {
+                    // Ranges that can't be merged have a gap between them
+                    let (low, high) = if a.from <= b.from { (a, b) } else { (b, a) };
+                    assert!(low.to < high.from - 1, "{a:?} {b:?}");
+                }
+            }
+        }
     }
 
     #[test]
     fn test_parse() {
         let parsed: Range = "1-10".parse().expect("successful parse");
         assert_eq!(parsed, Range::new(1, 10));
+
+        let err = "10".parse::<Range>().unwrap_err();
+        assert_eq!((err.column(), err.text()), (1, "10"));
+        let err = "1-1x".parse::<Range>().unwrap_err();
+        assert_eq!((err.column(), err.text()), (3, "1x"));
+        let err = "10-1".parse::<Range>().unwrap_err();
+        assert_eq!((err.column(), err.text()), (1, "10-1"));
     }
 
     #[test]
@@ -107,5 +180,8 @@ mod tests {
 
         let range = Range::new(100, 200);
         assert_eq!(range.size(), 101);
+
+        let range = Range::new(0, u64::MAX);
+        assert_eq!(range.size(), 1 << 64);
     }
 }
@@ -0,0 +1,94 @@
+//! The slow, obvious way to solve the puzzle: check every range, every time
+
+use common::ParseError;
+
+/// A fresh range of IDs, from and to inclusive
+struct Range {
+    from: u64,
+    to: u64,
+}
+
+fn parse(input: &str) -> Result<(Vec<Range>, Vec<u64>), ParseError> {
+    let mut lines = input.trim().lines();
+    let mut ranges = Vec::new();
+    for line in lines.by_ref() {
+        if line.trim().is_empty() {
+            break;
+        }
+        let range = line.split_once('-').and_then(|(from, to)| {
+            let (from, to) = (from.parse().ok()?, to.parse().ok()?);
+            (from <= to).then_some(Range { from, to })
+        });
+        ranges.push(range.ok_or_else(|| ParseError::new(line, "expected a range like 3-5"))?);
+    }
+
+    let mut ids = Vec::new();
+    for line in lines {
+        let id = line
+            .parse()
+            .map_err(|_| ParseError::new(line, "expected an ingredient ID"))?;
+        ids.push(id);
+    }
+    Ok((ranges, ids))
+}
+
+/// Count the available ingredient IDs that fall within any fresh range
+pub fn part_one(input: &str) -> Result<usize, ParseError> {
+    let (ranges, ids) = parse(input)?;
+    Ok(ids
+        .iter()
+        .filter(|&&id| {
+            ranges
+                .iter()
+                .any(|range| range.from <= id && id <= range.to)
+        })
+        .count())
+}
+
+/// Count every ID that is considered fresh by at least one range
+///
+/// Every range starts and ends on a boundary, so between two neighboring boundaries either every
+/// ID is fresh or none of them are.
+pub fn part_two(input: &str) -> Result<u128, ParseError> {
+    let (ranges, _) = parse(input)?;
+    let mut boundaries = ranges
+        .iter()
+        .flat_map(|range| [range.from as u128, range.to as u128 + 1])
+        .collect::<Vec<_>>();
+    boundaries.sort();
+    boundaries.dedup();
+
+    let mut total = 0;
+    for pair in boundaries.windows(2) {
+        let (start, end) = (pair[0], pair[1]);
+        let fresh = ranges
+            .iter()
+            .any(|range| range.from as u128 <= start && start <= range.to as u128);
+        if fresh {
+            total += end - start;
+        }
+    }
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day05;
+    use common::{Part, differential};
+
+    #[test]
+    fn test_example() {
+        let input = include_str!("example.txt");
+        assert_eq!(part_one(input), Ok(3));
+        assert_eq!(part_two(input), Ok(14));
+    }
+
+    #[test]
+    fn test_differential() {
+        for part in Part::ALL {
+            let disagreement = differential::check(&Day05, part, 0..50, 40);
+            assert!(disagreement.is_none(), "{disagreement:?}");
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use common::Rng;
+
+/// The shape of a generated worksheet
+#[derive(Clone, Debug)]
+pub struct GeneratorConfig {
+    /// How many problems to write side by side
+    pub problems: usize,
+    /// How many numbers each problem has
+    pub rows: usize,
+    /// The most digits a number can have
+    pub max_digits: u32,
+}
+
+impl GeneratorConfig {
+    /// A config for `size` problems
+    pub fn with_size(size: usize) -> Self {
+        Self {
+            problems: size,
+            rows: 4,
+            max_digits: 4,
+        }
+    }
+}
+
+/// Write a worksheet of problems in columns, with the operations along the bottom
+///
+/// Within a problem the numbers are either all left or all right aligned, and every row is padded
+/// to the full width. The numbers are ordered by length so that the digits in each column are
+/// never split by a gap.
+///
+/// At least one problem is always written, so that there is a row of operations to parse, and
+/// every number has at least one digit.
+pub fn generate(seed: u64, config: &GeneratorConfig) -> String {
+    let mut rng = Rng::new(seed);
+    let mut rows = vec![String::new(); config.rows];
+    let mut operations = String::new();
+
+    for problem in 0..config.problems.max(1) {
+        let mut numbers = (0..config.rows)
+            .map(|_| {
+                let digits = rng.range(1, config.max_digits.max(1) as u64);
+                (0..digits)
+                    .map(|_| (b'0' + rng.range(1, 9) as u8) as char)
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+        numbers.sort_by_key(|number| number.len());
+        if rng.chance(50) {
+            numbers.reverse();
+        }
+        let width = numbers.iter().map(String::len).max().unwrap_or(1);
+        let left_aligned = rng.chance(50);
+
+        if problem > 0 {
+            for row in &mut rows {
+                row.push(' ');
+            }
+            operations.push(' ');
+        }
+        for (row, number) in rows.iter_mut().zip(&numbers) {
+            if left_aligned {
+                row.push_str(&format!("{number:<width$}"));
+            } else {
+                row.push_str(&format!("{number:>width$}"));
+            }
+        }
+        let operation = if rng.chance(50) { '+' } else { '*' };
+        operations.push_str(&format!("{operation:<width$}"));
+    }
+
+    let mut out = String::new();
+    for row in rows {
+        out.push_str(&row);
+        out.push('\n');
+    }
+    out.push_str(&operations);
+    out.push('\n');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Roll;
+
+    #[test]
+    fn test_generate() {
+        let config = GeneratorConfig::with_size(30);
+        let input = generate(3, &config);
+        let roll = Roll::try_from(input.as_str()).unwrap();
+        assert_eq!(roll.problem_ranges().count(), 30);
+        for range in roll.problem_ranges() {
+            assert!(range.len() <= config.max_digits as usize);
+            for column in range {
+                let number = roll.number_at_column(column).unwrap();
+                assert!(number < 10_usize.pow(config.rows as u32));
+            }
+        }
+        crate::part_one(&input).unwrap();
+        crate::part_two(&input).unwrap();
+    }
+
+    #[test]
+    fn test_generate_edges() {
+        let input = generate(3, &GeneratorConfig::with_size(0));
+        let roll = Roll::try_from(input.as_str()).unwrap();
+        assert_eq!(roll.problem_ranges().count(), 1);
+        crate::part_one(&input).unwrap();
+        crate::part_two(&input).unwrap();
+
+        let config = GeneratorConfig {
+            problems: 5,
+            rows: 3,
+            max_digits: 0,
+        };
+        let input = generate(3, &config);
+        assert_eq!(input.lines().next().unwrap().len(), 9);
+        crate::part_two(&input).unwrap();
+    }
+}
@@ -0,0 +1,181 @@
+//! Day 6: solving the cephalopod math worksheet
+
+use common::{Answer, ParseError, Parsed, Part, Solver};
+
+pub use generate::{GeneratorConfig, generate};
+pub use roll::{ProblemRangesIter, Roll};
+
+mod generate;
+pub mod reference;
+mod roll;
+
+/// The puzzle input
+pub const INPUT: &str = include_str!("input.txt");
+
+/// The [`Solver`] for day 6
+pub struct Day06;
+
+impl Solver for Day06 {
+    fn day(&self) -> u8 {
+        6
+    }
+
+    fn input(&self) -> &'static str {
+        INPUT
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Box<dyn Parsed + 'a>, ParseError> {
+        let roll = Roll::try_from(input)?;
+        Ok(Box::new(Worksheet { input, roll }))
+    }
+
+    fn part_one(&self, input: &str) -> Result<Answer, ParseError> {
+        part_one(input).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, ParseError> {
+        part_two(input).map(Answer::from)
+    }
+
+    fn generate(&self, seed: u64, size: usize) -> String {
+        generate(seed, &GeneratorConfig::with_size(size))
+    }
+
+    fn reference(&self, part: Part, input: &str) -> Result<Answer, ParseError> {
+        match part {
+            Part::One => reference::part_one(input).map(Answer::from),
+            Part::Two => reference::part_two(input).map(Answer::from),
+        }
+    }
+}
+
+/// Sum the answers to every problem, reading the numbers row by row
+pub fn part_one(input: &str) -> Result<usize, ParseError> {
+    sum_rows(input, &Roll::try_from(input)?)
+}
+
+/// Sum the answers to every problem, reading the numbers column by column
+pub fn part_two(input: &str) -> Result<usize, ParseError> {
+    sum_columns(input, &Roll::try_from(input)?)
+}
+
+/// A worksheet once it has been parsed, with the text it came from so that errors can point at it
+struct Worksheet<'a> {
+    input: &'a str,
+    roll: Roll<'a>,
+}
+
+impl Parsed for Worksheet<'_> {
+    fn part_one(&self) -> Result<Answer, ParseError> {
+        sum_rows(self.input, &self.roll).map(Answer::from)
+    }
+
+    fn part_two(&self) -> Result<Answer, ParseError> {
+        sum_columns(self.input, &self.roll).map(Answer::from)
+    }
+}
+
+/// Sum the answers to every problem in `roll`, reading the numbers row by row
+fn sum_rows(input: &str, roll: &Roll) -> Result<usize, ParseError> {
+    let mut stacks: Vec<Vec<usize>> = Vec::new();
+    for row in roll.rows() {
+        for (idx, val) in row.split_ascii_whitespace().enumerate() {
+            if idx >= stacks.len() {
+                stacks.push(Vec::new());
+            }
+            let num = val
+                .parse()
+                .map_err(|_| ParseError::new(val, "expected a number").within(input, val))?;
+            stacks[idx].push(num);
+        }
+    }
+
+    let mut sum: usize = 0;
+    for (idx, operation) in roll.operations().split_ascii_whitespace().enumerate() {
+        let stack = stacks.get(idx).map_or(&[][..], Vec::as_slice);
+        let answer = match operation {
+            "*" => stack.iter().try_fold(1_usize, |a, &b| a.checked_mul(b)),
+            "+" => stack.iter().try_fold(0_usize, |a, &b| a.checked_add(b)),
+            _ => {
+                return Err(ParseError::new(operation, "expected an operation, + or *")
+                    .within(input, operation));
+            }
+        };
+        sum = answer
+            .and_then(|answer| sum.checked_add(answer))
+            .ok_or_else(|| {
+                ParseError::new(operation, "answer is too big").within(input, operation)
+            })?;
+    }
+
+    Ok(sum)
+}
+
+/// Sum the answers to every problem in `roll`, reading the numbers column by column
+fn sum_columns(input: &str, roll: &Roll) -> Result<usize, ParseError> {
+    let mut sum: usize = 0;
+
+    for problem in roll.problem_ranges() {
+        let mut numbers = Vec::new();
+        let start = problem.start;
+        for column in problem {
+            numbers.push(roll.number_at_column(column)?);
+        }
+        let operations = roll.operations();
+        let operation = &operations[start..start + 1];
+        let solution = match operation {
+            "+" => numbers.iter().try_fold(0_usize, |a, &b| a.checked_add(b)),
+            "*" => numbers.iter().try_fold(1_usize, |a, &b| a.checked_mul(b)),
+            _ => {
+                return Err(ParseError::new(operation, "expected an operation, + or *")
+                    .within(input, operation));
+            }
+        };
+
+        sum = solution
+            .and_then(|solution| sum.checked_add(solution))
+            .ok_or_else(|| {
+                ParseError::new(operation, "answer is too big").within(input, operation)
+            })?;
+    }
+
+    Ok(sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = include_str!("example.txt");
+        let value = part_one(input).unwrap();
+        assert_eq!(value, 4277556);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = include_str!("example.txt");
+        let value = part_two(input).unwrap();
+        assert_eq!(value, 3263827);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = part_one("123 328\n 45 6x\n*   +\n").unwrap_err();
+        assert_eq!((err.line(), err.column(), err.text()), (2, 5, "6x"));
+
+        let input = "99999999999 1\n99999999999 2\n*           +\n";
+        let err = part_one(input).unwrap_err();
+        assert_eq!(
+            (err.line(), err.column(), err.reason()),
+            (3, 1, "answer is too big")
+        );
+        let input = "9999999999 1\n9999999999 2\n*          +\n";
+        let err = part_two(input).unwrap_err();
+        assert_eq!(
+            (err.line(), err.column(), err.reason()),
+            (3, 1, "answer is too big")
+        );
+    }
+}
@@ -0,0 +1,129 @@
+//! The slow, obvious way to solve the puzzle: treat the worksheet as a grid of characters
+
+use common::ParseError;
+
+/// The rows of numbers and the row of operations under them
+fn split(input: &str) -> Result<(Vec<&str>, &str), ParseError> {
+    let mut rows = Vec::new();
+    for line in input.lines() {
+        if !line.starts_with(['+', '*']) {
+            rows.push(line);
+            continue;
+        }
+        if let Some(c) = line.chars().find(|c| !matches!(c, ' ' | '+' | '*')) {
+            return Err(ParseError::new(
+                line,
+                format!("unexpected {c:?} in the operations"),
+            ));
+        }
+        return Ok((rows, line));
+    }
+    Err(ParseError::new(input, "expected a row of operations"))
+}
+
+fn apply(operation: char, numbers: &[usize]) -> Result<usize, ParseError> {
+    let answer = match operation {
+        '+' => numbers.iter().try_fold(0_usize, |a, &b| a.checked_add(b)),
+        _ => numbers.iter().try_fold(1_usize, |a, &b| a.checked_mul(b)),
+    };
+    answer.ok_or_else(|| ParseError::new(operation, "answer is too big"))
+}
+
+/// Sum the answers to every problem, reading the numbers row by row
+pub fn part_one(input: &str) -> Result<usize, ParseError> {
+    let (rows, operations) = split(input)?;
+    let mut numbers = Vec::new();
+    for row in rows {
+        let row = row
+            .split_whitespace()
+            .map(|number| {
+                number
+                    .parse()
+                    .map_err(|_| ParseError::new(number, "expected a number"))
+            })
+            .collect::<Result<Vec<usize>, _>>()?;
+        numbers.push(row);
+    }
+
+    let mut sum: usize = 0;
+    for (column, operation) in operations.split_whitespace().enumerate() {
+        let numbers = numbers
+            .iter()
+            .filter_map(|row| row.get(column).copied())
+            .collect::<Vec<_>>();
+        let answer = apply(operation.chars().next().unwrap(), &numbers)?;
+        sum = sum
+            .checked_add(answer)
+            .ok_or_else(|| ParseError::new(operation, "answer is too big"))?;
+    }
+    Ok(sum)
+}
+
+/// Sum the answers to every problem, reading the numbers column by column
+pub fn part_two(input: &str) -> Result<usize, ParseError> {
+    let (rows, operations) = split(input)?;
+    let grid = rows
+        .iter()
+        .map(|row| row.chars().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    if let Some(&c) = grid
+        .iter()
+        .flatten()
+        .find(|c| !matches!(c, ' ' | '0'..='9'))
+    {
+        return Err(ParseError::new(c, "expected a digit"));
+    }
+    let operations = operations.chars().collect::<Vec<_>>();
+    let width = grid.iter().map(Vec::len).max().unwrap_or(0);
+    let at = |row: &[char], column: usize| row.get(column).copied().unwrap_or(' ');
+
+    let mut sum: usize = 0;
+    let mut operation = '+';
+    let mut numbers = Vec::new();
+    for column in 0..=width {
+        let digits = grid
+            .iter()
+            .map(|row| at(row, column))
+            .filter(|c| c.is_ascii_digit())
+            .collect::<String>();
+        if digits.is_empty() {
+            if !numbers.is_empty() {
+                sum = sum
+                    .checked_add(apply(operation, &numbers)?)
+                    .ok_or_else(|| ParseError::new(operation, "answer is too big"))?;
+                numbers.clear();
+            }
+            continue;
+        }
+        if numbers.is_empty() {
+            operation = at(&operations, column);
+        }
+        let number = digits
+            .parse()
+            .map_err(|_| ParseError::new(digits.as_str(), "number is too big"))?;
+        numbers.push(number);
+    }
+    Ok(sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day06;
+    use common::{Part, differential};
+
+    #[test]
+    fn test_example() {
+        let input = include_str!("example.txt");
+        assert_eq!(part_one(input), Ok(4277556));
+        assert_eq!(part_two(input), Ok(3263827));
+    }
+
+    #[test]
+    fn test_differential() {
+        for part in Part::ALL {
+            let disagreement = differential::check(&Day06, part, 0..50, 20);
+            assert!(disagreement.is_none(), "{disagreement:?}");
+        }
+    }
+}
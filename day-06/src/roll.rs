@@ -0,0 +1,229 @@
+use common::ParseError;
+use std::ops::Range;
+
+/// A worksheet of numbers in columns, with a row of operations at the bottom
+pub struct Roll<'a> {
+    rows: Vec<&'a str>,
+    operations: &'a str,
+}
+
+impl<'a> TryFrom<&'a str> for Roll<'a> {
+    type Error = ParseError;
+
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        let mut numbers = Vec::new();
+        for line in value.lines() {
+            if line.starts_with("*") || line.starts_with("+") {
+                if let Some((column, c)) = line
+                    .chars()
+                    .enumerate()
+                    .find(|(_, c)| !matches!(c, ' ' | '+' | '*'))
+                {
+                    return Err(ParseError::new(c, "expected an operation, + or *")
+                        .at(1, column + 1)
+                        .within(value, line));
+                }
+                return Ok(Roll {
+                    rows: numbers,
+                    operations: line,
+                });
+            } else {
+                numbers.push(line);
+            }
+        }
+
+        let last = value.lines().last().unwrap_or(value);
+        Err(ParseError::new(last, "expected a row of operations at the bottom").within(value, last))
+    }
+}
+
+impl<'a> Roll<'a> {
+    /// The rows of numbers above the operations, top to bottom
+    pub fn rows(&self) -> &[&'a str] {
+        &self.rows
+    }
+
+    /// The row of `+` and `*` operations
+    pub fn operations(&self) -> &'a str {
+        self.operations
+    }
+
+    /// Read the number written top to bottom in a single column
+    ///
+    /// Fails if a row doesn't reach the column, has something other than a digit in it, or the
+    /// number is too big for a `usize`.
+    pub fn number_at_column(&self, column: usize) -> Result<usize, ParseError> {
+        let mut num: usize = 0;
+        for (line, row) in self.rows.iter().enumerate() {
+            match row.as_bytes().get(column) {
+                None => {
+                    return Err(ParseError::new(
+                        *row,
+                        format!("expected a row at least {} wide", column + 1),
+                    )
+                    .at(line + 1, 1));
+                }
+                Some(b' ') if num > 0 => {
+                    break;
+                }
+                Some(b' ') => {}
+                Some(v @ b'0'..=b'9') => {
+                    let digit = (v - b'0') as usize;
+                    num = num
+                        .checked_mul(10)
+                        .and_then(|num| num.checked_add(digit))
+                        .ok_or_else(|| {
+                            ParseError::new(&row[column..=column], "number is too big")
+                                .at(line + 1, row[..column].chars().count() + 1)
+                        })?;
+                }
+                Some(_) => {
+                    // The column counts bytes, so it can land in the middle of a character
+                    let (start, c) = row
+                        .char_indices()
+                        .rfind(|&(i, _)| i <= column)
+                        .unwrap_or_default();
+                    let column = row[..start].chars().count() + 1;
+                    return Err(ParseError::new(c, "expected a digit").at(line + 1, column));
+                }
+            }
+        }
+        Ok(num)
+    }
+
+    /// The ranges of columns that make up each problem
+    pub fn problem_ranges(&self) -> ProblemRangesIter<'_> {
+        ProblemRangesIter::new(self.operations)
+    }
+}
+
+/// The columns of each problem, found from the positions of the operations
+pub struct ProblemRangesIter<'a> {
+    str: &'a [u8],
+    next_start: usize,
+}
+
+impl<'a> ProblemRangesIter<'a> {
+    /// Iterate over the problems in a row of operations
+    ///
+    /// The row must only contain spaces, `+` and `*`; [`Roll`] checks this when it is parsed.
+    pub(crate) fn new(str: &'a str) -> Self {
+        Self {
+            str: str.as_bytes(),
+            next_start: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for ProblemRangesIter<'a> {
+    type Item = Range<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next_start >= self.str.len() {
+            return None;
+        }
+
+        let start = self.next_start;
+        loop {
+            self.next_start += 1;
+            if self.next_start >= self.str.len() {
+                self.next_start += 1;
+                break;
+            }
+
+            match self.str[self.next_start] {
+                b' ' => {}
+                b'+' | b'*' => {
+                    break;
+                }
+                c => {
+                    panic!("Unexpected char {c:x} in problem ranges iter");
+                }
+            }
+        }
+
+        Some(start..self.next_start - 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roll_number_at_column() {
+        let input = r#"
+1     1 124  
+24   23  35  
+356 456   6 4
++   +   +    "#;
+        // Remove leading newline
+        let input = &input[1..];
+        assert!(input.starts_with("1"));
+
+        let roll = Roll::try_from(input).unwrap();
+        assert_eq!(roll.number_at_column(0), Ok(123));
+        assert_eq!(roll.number_at_column(1), Ok(45));
+        assert_eq!(roll.number_at_column(2), Ok(6));
+
+        assert_eq!(roll.number_at_column(4), Ok(4));
+        assert_eq!(roll.number_at_column(5), Ok(25));
+        assert_eq!(roll.number_at_column(6), Ok(136));
+
+        assert_eq!(roll.number_at_column(8), Ok(1));
+        assert_eq!(roll.number_at_column(9), Ok(23));
+        assert_eq!(roll.number_at_column(10), Ok(456));
+
+        assert_eq!(roll.number_at_column(12), Ok(4));
+    }
+
+    #[test]
+    fn test_roll_problem_ranges() {
+        let input = r#"
+1     1
+24   23
+356 456
++   +  "#;
+        // Remove leading newline
+        let input = &input[1..];
+        assert!(input.starts_with("1"));
+
+        let roll = Roll::try_from(input).unwrap();
+        let mut ranges = roll.problem_ranges();
+        assert_eq!(ranges.next(), Some(0..3));
+        assert_eq!(ranges.next(), Some(4..7));
+        assert_eq!(ranges.next(), None);
+    }
+
+    #[test]
+    fn test_roll_errors() {
+        let err = Roll::try_from("1 2\n3 4\n").err().unwrap();
+        assert_eq!((err.line(), err.column(), err.text()), (2, 1, "3 4"));
+
+        let err = Roll::try_from("1 2\n3 4\n+ -\n").err().unwrap();
+        assert_eq!((err.line(), err.column(), err.text()), (3, 3, "-"));
+
+        let roll = Roll::try_from("1 2\n3x4\n3\n+ *\n").unwrap();
+        let err = roll.number_at_column(1).unwrap_err();
+        assert_eq!((err.line(), err.column(), err.text()), (2, 2, "x"));
+        let err = roll.number_at_column(2).unwrap_err();
+        assert_eq!((err.line(), err.column(), err.text()), (3, 1, "3"));
+
+        let roll = Roll::try_from("1 \n  \né\n+ \n").unwrap();
+        let err = roll.number_at_column(1).unwrap_err();
+        assert_eq!((err.line(), err.column(), err.text()), (3, 1, "é"));
+
+        // Columns count characters, not bytes
+        let roll = Roll::try_from(" 1 \néx3\n+  *\n").unwrap();
+        let err = roll.number_at_column(2).unwrap_err();
+        assert_eq!((err.line(), err.column(), err.text()), (2, 2, "x"));
+
+        let input = "9\n".repeat(20) + "+\n";
+        let roll = Roll::try_from(input.as_str()).unwrap();
+        let err = roll.number_at_column(0).unwrap_err();
+        assert_eq!(
+            (err.line(), err.column(), err.reason()),
+            (20, 1, "number is too big")
+        );
+    }
+}